@@ -0,0 +1,97 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+// Consts for the config files, the player's copy is kept beside the shipped defaults
+const CONFIG_FILE: &str = "config.json";
+const DEFAULT_CONFIG_FILE: &str = "config.default.json";
+
+// Struct for Config Manager
+pub struct ConfigManager {
+    pub config: Config,
+    pub keymap: super::keymap::Keymap,
+    pub theme: crate::ui::theme::Theme,
+}
+
+// Functions for Config Manager
+impl ConfigManager {
+    // Create a new Config Manager, loading the config file if there is one
+    pub fn new() -> Self {
+        let config = match load_config() {
+            Ok(config) => config,
+            Err(e) => {
+                log::error!("Failed to load config: {}", e);
+                Config::default()
+            }
+        };
+        let keymap = super::keymap::Keymap::new(&config.keymap);
+        let theme = crate::ui::theme::Theme::new(&config.theme);
+
+        Self {
+            config,
+            keymap,
+            theme,
+        }
+    }
+
+    // Switch to a keymap preset and save it to the config file
+    pub fn set_preset(&mut self, preset: super::keymap::Preset) {
+        self.config.keymap.preset = preset;
+        self.keymap = super::keymap::Keymap::new(&self.config.keymap);
+
+        if let Err(e) = self.save() {
+            log::error!("Failed to save config: {}", e);
+        }
+    }
+
+    // Switch to a theme palette and save it to the config file
+    pub fn set_palette(&mut self, palette: crate::ui::theme::Palette) {
+        self.config.theme = palette;
+        self.theme = crate::ui::theme::Theme::new(&self.config.theme);
+
+        if let Err(e) = self.save() {
+            log::error!("Failed to save config: {}", e);
+        }
+    }
+
+    // Save the config to JSON
+    fn save(&self) -> Result<(), std::io::Error> {
+        let json = serde_json::to_string_pretty(&self.config)?;
+
+        let config_path = PathBuf::from("config").join(CONFIG_FILE);
+
+        if let Some(parent) = config_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(&config_path, json)?;
+
+        Ok(())
+    }
+}
+
+// Load the player's config file, falling back to the shipped defaults and then the built-in ones
+fn load_config() -> Result<Config, Box<dyn std::error::Error>> {
+    for file_name in [CONFIG_FILE, DEFAULT_CONFIG_FILE] {
+        let config_path = PathBuf::from("config").join(file_name);
+
+        if config_path.exists() {
+            let json_data = fs::read_to_string(config_path)?;
+
+            let config: Config = serde_json::from_str(&json_data)?;
+
+            return Ok(config);
+        }
+    }
+
+    Ok(Config::default())
+}
+
+// Struct for the config file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Config {
+    #[serde(default)]
+    pub keymap: super::keymap::KeymapConfig,
+    #[serde(default)]
+    pub theme: crate::ui::theme::Palette,
+}
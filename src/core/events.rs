@@ -1,8 +1,60 @@
+use crate::core::keymap::Action;
+use crate::entities::condition::{Ailment, BlessingType};
+use crate::entities::player::QuestStatus;
 use core::panic;
-use crossterm::event::{self, Event, KeyCode};
+use crossterm::event::{self, Event, KeyCode, MouseButton, MouseEvent, MouseEventKind};
 use std::collections::HashMap;
 use std::io;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
+use strum::IntoEnumIterator;
+
+// Const for gold at the start of a New Game
+const STARTING_GOLD: u32 = 50;
+
+// Consts for lockpicking
+const LOCKPICK_TICKS: u32 = 10;
+const LOCKPICK_WITNESS_CHANCE: u32 = 15;
+const LOCKPICK_BOUNTY: u32 = 25;
+
+// Consts for the damage to standing when caught stealing
+const THEFT_REPUTATION: i32 = -5;
+const THEFT_DISPOSITION: i32 = -10;
+
+// Const for how far trading can warm a shopkeeper to the player
+const TRADE_DISPOSITION_LIMIT: i32 = 5;
+
+// Const for the bonus to lockpicking rolls from the Blessing of Fortune
+const FORTUNE_BONUS: u32 = 2;
+
+// Consts for health restored by medicine
+const BANDAGE_HEAL: u32 = 5;
+const POTION_HEAL: u32 = 15;
+
+// Consts for mishaps on the road
+const MISHAP_DAMAGE: u32 = 8;
+const COLLAPSE_TICKS: u32 = 300;
+
+// Consts for hunger eased by food and fatigue eased by drink
+const FOOD_NOURISHMENT: u32 = 25;
+const DRINK_REFRESHMENT: u32 = 10;
+
+// Const for the extra travel time per level of hunger or fatigue, as a percentage
+const NEED_TRAVEL_PERCENT: u32 = 25;
+
+// Const for the ticks of walking it takes to gain a point of fatigue, multiplied by stamina
+const WALKING_TICKS_PER_FATIGUE: u32 = 4;
+
+// Consts for experience from exploring a building for the first time and from travelling
+const EXPLORE_EXPERIENCE: u32 = 10;
+const TRAVEL_TICKS_PER_EXPERIENCE: u32 = 50;
+
+// Consts for what each rank of a skill is worth, to lockpicking rolls and off walking time
+const LOCKPICKING_BONUS: u32 = 2;
+const PATHFINDING_PERCENT: u32 = 10;
+
+// Const for the menu options that lead back out of a menu
+const MENU_EXITS: [&str; 3] = ["Back", "Goodbye", "No"];
 
 // Struct for Event Handler
 pub struct EventHandler {}
@@ -14,6 +66,20 @@ impl EventHandler {
         managers: &mut crate::ui::display::Managers,
         ui_components: &mut crate::ui::display::UIComponents,
     ) -> io::Result<bool> {
+        // Help is shown over every screen, any key or click closes it
+        if ui_components.help.display {
+            if let Event::Key(_)
+            | Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Down(_),
+                ..
+            }) = event::read()?
+            {
+                ui_components.help.display = false;
+            }
+
+            return Ok(true);
+        }
+
         match managers.state_manager.current_state {
             // New Game (Enter Name)
             super::states::StateType::Name => {
@@ -23,17 +89,20 @@ impl EventHandler {
                         KeyCode::Backspace => {
                             ui_components.popup.input.pop();
                         }
-                        KeyCode::Enter => {
-                            managers.state_manager.current_state =
-                                super::states::StateType::NameConfirm;
-                        }
-                        KeyCode::Esc => {
-                            ui_components.popup.input.clear();
+                        key_code => match managers.config_manager.keymap.action(&key_code) {
+                            Some(Action::Select) => {
+                                managers.state_manager.current_state =
+                                    super::states::StateType::NameConfirm;
+                            }
+                            Some(Action::Back) => {
+                                ui_components.popup.input.clear();
 
-                            managers.state_manager.current_state =
-                                super::states::StateType::MainMenu;
-                        }
-                        _ => {}
+                                managers.state_manager.current_state =
+                                    super::states::StateType::MainMenu;
+                            }
+                            Some(Action::Help) => ui_components.help.display = true,
+                            _ => {}
+                        },
                     }
                 }
 
@@ -42,8 +111,8 @@ impl EventHandler {
             // New Game (Confirm Name)
             super::states::StateType::NameConfirm => {
                 if let Event::Key(key) = event::read()? {
-                    match key.code {
-                        KeyCode::Enter => {
+                    match managers.config_manager.keymap.action(&key.code) {
+                        Some(Action::Select) => {
                             // Start the game
                             match start_game(managers, ui_components) {
                                 Ok(()) => {
@@ -55,6 +124,11 @@ impl EventHandler {
                                 Err(e) => {
                                     log::error!("Failed to load game assets: {}", e);
 
+                                    record_message(
+                                        managers,
+                                        &format!("Failed to load game assets: {}", e),
+                                    );
+
                                     ui_components.popup.input.clear();
 
                                     managers.state_manager.current_state =
@@ -62,11 +136,12 @@ impl EventHandler {
                                 }
                             }
                         }
-                        KeyCode::Esc => {
+                        Some(Action::Back) => {
                             ui_components.popup.input.clear();
 
                             managers.state_manager.current_state = super::states::StateType::Name;
                         }
+                        Some(Action::Help) => ui_components.help.display = true,
                         _ => {}
                     }
                 }
@@ -75,9 +150,59 @@ impl EventHandler {
             }
             // Save Game
             super::states::StateType::GameSaveSuccess | super::states::StateType::GameSaveError => {
-                if let Event::Key(key) = event::read()? {
-                    if key.code == KeyCode::Enter {
-                        managers.state_manager.current_state = super::states::StateType::Game;
+                if dismissed(managers, ui_components, event::read()?) {
+                    managers.state_manager.current_state =
+                        managers.state_manager.back_state.clone();
+                }
+
+                Ok(true)
+            }
+            // Notice
+            super::states::StateType::Notice => {
+                if dismissed(managers, ui_components, event::read()?) {
+                    ui_components.popup.message.clear();
+
+                    managers.state_manager.current_state =
+                        managers.state_manager.return_state.clone();
+                }
+
+                Ok(true)
+            }
+            // Directory (type to search)
+            super::states::StateType::Directory => {
+                if event::poll(Duration::ZERO)? {
+                    match event::read()? {
+                        Event::Key(key) => match key.code {
+                            KeyCode::Char(c) => {
+                                managers.state_manager.search_query.push(c);
+                                ui_components.menu.selected_index = 0;
+                                ui_components.menu.scroll_offset = 0;
+                            }
+                            KeyCode::Backspace => {
+                                managers.state_manager.search_query.pop();
+                                ui_components.menu.selected_index = 0;
+                                ui_components.menu.scroll_offset = 0;
+                            }
+                            key_code => match managers.config_manager.keymap.action(&key_code) {
+                                Some(action) if navigate(managers, ui_components, &action) => {}
+                                Some(Action::Help) => ui_components.help.display = true,
+                                Some(Action::Select) => {
+                                    select(managers, ui_components)?;
+                                }
+                                Some(Action::Back) => {
+                                    managers.state_manager.search_query.clear();
+
+                                    managers.state_manager.current_state =
+                                        super::states::StateType::Game;
+                                    ui_components.menu.selected_index = 0;
+                                }
+                                _ => {}
+                            },
+                        },
+                        Event::Mouse(mouse_event) => {
+                            mouse(managers, ui_components, mouse_event)?;
+                        }
+                        _ => {}
                     }
                 }
 
@@ -85,10 +210,8 @@ impl EventHandler {
             }
             // Load Game (Error) and Initialize Game (Error)
             super::states::StateType::GameLoadError | super::states::StateType::GameInitError => {
-                if let Event::Key(key) = event::read()? {
-                    if key.code == KeyCode::Enter {
-                        managers.state_manager.current_state = super::states::StateType::MainMenu;
-                    }
+                if dismissed(managers, ui_components, event::read()?) {
+                    managers.state_manager.current_state = super::states::StateType::MainMenu;
                 }
 
                 Ok(true)
@@ -96,15 +219,61 @@ impl EventHandler {
             // All other states (these use the Select function)
             _ => {
                 if event::poll(Duration::ZERO)? {
-                    if let Event::Key(key) = event::read()? {
-                        match key.code {
-                            KeyCode::Up => ui_components.menu.previous(),
-                            KeyCode::Down => ui_components.menu.next(),
-                            KeyCode::Enter => {
-                                if !select(managers, ui_components)? {
+                    let event = event::read()?;
+
+                    if let Event::Mouse(mouse_event) = event {
+                        return mouse(managers, ui_components, mouse_event);
+                    }
+
+                    if let Event::Key(key) = event {
+                        if filter_menu(managers, ui_components, &key.code) {
+                            return Ok(true);
+                        }
+
+                        let in_game = shortcuts_enabled(managers);
+
+                        match managers.config_manager.keymap.action(&key.code) {
+                            Some(action) if navigate(managers, ui_components, &action) => {}
+                            Some(Action::Help) => ui_components.help.display = true,
+                            Some(Action::Select) if ui_components.menu.has_selection() => {
+                                let keep_running = select(managers, ui_components)?;
+
+                                if !keep_running {
+                                    return Ok(false);
+                                }
+                            }
+                            Some(Action::Back) => {
+                                let keep_running = back(managers, ui_components)?;
+
+                                if !keep_running {
                                     return Ok(false);
                                 }
                             }
+                            Some(Action::Save) if in_game => {
+                                managers.state_manager.back_state =
+                                    managers.state_manager.current_state.clone();
+                                save_game(managers);
+                            }
+                            Some(Action::Quit) if in_game => {
+                                managers.state_manager.back_state =
+                                    managers.state_manager.current_state.clone();
+                                managers.state_manager.current_state =
+                                    super::states::StateType::GameQuit;
+                                ui_components.menu.selected_index = 0;
+                            }
+                            Some(Action::Map)
+                                if in_game
+                                    && !matches!(
+                                        managers.state_manager.current_state,
+                                        super::states::StateType::Map
+                                    ) =>
+                            {
+                                managers.state_manager.back_state =
+                                    managers.state_manager.current_state.clone();
+                                managers.state_manager.current_state =
+                                    super::states::StateType::Map;
+                                ui_components.menu.selected_index = 0;
+                            }
                             _ => {}
                         }
                     }
@@ -116,6 +285,262 @@ impl EventHandler {
     }
 }
 
+// Whether the save, quit and map shortcuts work, only during a game and not from the screen they open
+pub fn shortcuts_enabled(managers: &crate::ui::display::Managers) -> bool {
+    managers.world_manager.player.is_some()
+        && !matches!(
+            managers.state_manager.current_state,
+            super::states::StateType::MainMenu
+                | super::states::StateType::Keybindings
+                | super::states::StateType::Themes
+                | super::states::StateType::GameQuit
+        )
+}
+
+// Save the game, the result is shown as a popup
+fn save_game(managers: &mut crate::ui::display::Managers) {
+    managers.records_manager.flush();
+
+    match managers.save_manager.save(
+        &managers.world_manager,
+        &managers.time_manager,
+        &managers.weather_manager,
+        &managers.rng_manager,
+        &managers.records_manager,
+    ) {
+        Ok(()) => {
+            record_message(managers, "Game saved.");
+            managers.state_manager.current_state = super::states::StateType::GameSaveSuccess;
+        }
+        Err(e) => {
+            log::error!("Failed to save game: {}", e);
+
+            record_message(managers, &format!("Failed to save game: {}", e));
+
+            managers.state_manager.current_state = super::states::StateType::GameSaveError;
+        }
+    };
+}
+
+// Move around the menu, viewport and message log, returns whether the action was one of these
+fn navigate(
+    managers: &mut crate::ui::display::Managers,
+    ui_components: &mut crate::ui::display::UIComponents,
+    action: &Action,
+) -> bool {
+    match action {
+        Action::Up => ui_components.menu.previous(),
+        Action::Down => ui_components.menu.next(),
+        Action::PageUp => ui_components.menu.page_up(),
+        Action::PageDown => ui_components.menu.page_down(),
+        Action::First => ui_components.menu.first(),
+        Action::Last => ui_components.menu.last(),
+        Action::ViewUp => ui_components.viewport.scroll_up(),
+        Action::ViewDown => ui_components.viewport.scroll_down(),
+        Action::LogUp => ui_components.messages.scroll_up(managers),
+        Action::LogDown => ui_components.messages.scroll_down(),
+        Action::ExportLog => match managers.message_manager.export() {
+            Ok(path) => {
+                record_message(
+                    managers,
+                    &format!("Message log exported to {}.", path.display()),
+                );
+            }
+            Err(e) => {
+                log::error!("Failed to export message log: {}", e);
+
+                record_message(managers, &format!("Failed to export message log: {}", e));
+            }
+        },
+        Action::Stats => ui_components.show_stats = !ui_components.show_stats,
+        _ => return false,
+    }
+
+    true
+}
+
+// Type into the menu filter, returns whether the key was used by the filter
+fn filter_menu(
+    managers: &crate::ui::display::Managers,
+    ui_components: &mut crate::ui::display::UIComponents,
+    key_code: &KeyCode,
+) -> bool {
+    let action = managers.config_manager.keymap.action(key_code);
+
+    match (key_code, action) {
+        // While filtering every character is typed, even ones bound to actions
+        (KeyCode::Char(c), _) if ui_components.menu.is_filtering() => {
+            ui_components.menu.filter_push(*c);
+            true
+        }
+        (KeyCode::Backspace, _) if ui_components.menu.is_filtering() => {
+            ui_components.menu.filter_pop();
+            true
+        }
+        (_, Some(Action::Back)) if ui_components.menu.is_filtering() => {
+            ui_components.menu.clear_filter();
+            true
+        }
+        // Filtering only starts from its own key, most letters are bound to actions
+        (_, Some(Action::Filter)) => {
+            ui_components.menu.start_filter();
+            true
+        }
+        _ => false,
+    }
+}
+
+// Whether an event dismisses a popup, either by key or by clicking the menu
+fn dismissed(
+    managers: &crate::ui::display::Managers,
+    ui_components: &crate::ui::display::UIComponents,
+    event: Event,
+) -> bool {
+    match event {
+        Event::Key(key) => matches!(
+            managers.config_manager.keymap.action(&key.code),
+            Some(Action::Select | Action::Back)
+        ),
+        Event::Mouse(mouse_event) => {
+            matches!(mouse_event.kind, MouseEventKind::Down(MouseButton::Left))
+                && ui_components
+                    .menu
+                    .option_at(mouse_event.column, mouse_event.row)
+                    .is_some()
+        }
+        _ => false,
+    }
+}
+
+// Walk to a town, then show anything that happened on the road
+fn walk_to_town(
+    managers: &mut crate::ui::display::Managers,
+    ui_components: &mut crate::ui::display::UIComponents,
+    destination: &str,
+) {
+    let note = travel_to_town(managers, destination, false);
+
+    managers.state_manager.current_state = super::states::StateType::Game;
+    ui_components.menu.selected_index = 0;
+
+    let messages: Vec<String> = travel_mishap(managers).into_iter().chain(note).collect();
+
+    if !messages.is_empty() {
+        show_notice(managers, ui_components, messages.join("\n"));
+    }
+}
+
+// Handle a mouse event, clicks select menu options or towns on the map and the wheel scrolls
+fn mouse(
+    managers: &mut crate::ui::display::Managers,
+    ui_components: &mut crate::ui::display::UIComponents,
+    mouse_event: MouseEvent,
+) -> io::Result<bool> {
+    match mouse_event.kind {
+        MouseEventKind::Down(MouseButton::Left) => {
+            let (column, row) = (mouse_event.column, mouse_event.row);
+
+            if let Some(index) = ui_components.menu.option_at(column, row) {
+                ui_components.menu.selected_index = index;
+
+                return select(managers, ui_components);
+            }
+
+            if matches!(
+                managers.state_manager.current_state,
+                super::states::StateType::Map
+            ) {
+                if let Some(town) = ui_components.viewport.map_town_at(column, row) {
+                    managers.state_manager.selected_town = Some(town.clone());
+
+                    managers.state_manager.current_state = super::states::StateType::TravelConfirm;
+                    ui_components.menu.selected_index = 0;
+                }
+            }
+
+            Ok(true)
+        }
+        // The wheel scrolls whichever pane it's over, the menu if it's over neither of the others
+        MouseEventKind::ScrollDown => {
+            let (column, row) = (mouse_event.column, mouse_event.row);
+
+            if ui_components.viewport.contains(column, row) {
+                ui_components.viewport.scroll_down();
+            } else if ui_components.messages.contains(column, row) {
+                ui_components.messages.scroll_down();
+            } else {
+                ui_components.menu.next();
+            }
+
+            Ok(true)
+        }
+        MouseEventKind::ScrollUp => {
+            let (column, row) = (mouse_event.column, mouse_event.row);
+
+            if ui_components.viewport.contains(column, row) {
+                ui_components.viewport.scroll_up();
+            } else if ui_components.messages.contains(column, row) {
+                ui_components.messages.scroll_up(managers);
+            } else {
+                ui_components.menu.previous();
+            }
+
+            Ok(true)
+        }
+        _ => Ok(true),
+    }
+}
+
+// Go back from the current menu, the same as choosing its way out
+fn back(
+    managers: &mut crate::ui::display::Managers,
+    ui_components: &mut crate::ui::display::UIComponents,
+) -> io::Result<bool> {
+    match managers.state_manager.current_state {
+        // Nowhere to go back to from the Main Menu
+        super::states::StateType::MainMenu => Ok(true),
+        // Going back from the top of the game offers to quit
+        super::states::StateType::Game => {
+            managers.state_manager.back_state = super::states::StateType::Game;
+            managers.state_manager.current_state = super::states::StateType::GameQuit;
+            ui_components.menu.selected_index = 0;
+
+            Ok(true)
+        }
+        // Time and Weather share the Game menu
+        super::states::StateType::Time | super::states::StateType::Weather => {
+            managers.state_manager.current_state = super::states::StateType::Game;
+
+            Ok(true)
+        }
+        // Authored dialogue doesn't always offer a way out, so leave the conversation directly
+        super::states::StateType::Dialogue => {
+            managers.dialogue_manager.end();
+
+            managers.state_manager.current_state = super::states::StateType::Npc;
+            ui_components.menu.selected_index = 0;
+
+            Ok(true)
+        }
+        // Every other menu ends with its way out
+        _ => {
+            let exit = ui_components
+                .menu
+                .menu_options
+                .iter()
+                .rposition(|option| MENU_EXITS.contains(&option.as_str()));
+
+            match exit {
+                Some(index) => {
+                    ui_components.menu.selected_index = index;
+                    select(managers, ui_components)
+                }
+                None => Ok(true),
+            }
+        }
+    }
+}
+
 // Select the currently highlighted menu option
 fn select(
     managers: &mut crate::ui::display::Managers,
@@ -136,12 +561,23 @@ fn select(
                 Err(e) => {
                     log::error!("Failed to load save: {}", e);
 
+                    record_message(managers, &format!("Failed to load save: {}", e));
+
                     managers.state_manager.current_state =
                         crate::core::states::StateType::GameLoadError;
                     ui_components.menu.selected_index = 0;
                 }
             },
-            2 => return Ok(false),
+            2 => {
+                managers.state_manager.back_state = super::states::StateType::MainMenu;
+                managers.state_manager.current_state = super::states::StateType::Keybindings;
+                ui_components.menu.selected_index = 0;
+            }
+            3 => {
+                managers.state_manager.current_state = super::states::StateType::Themes;
+                ui_components.menu.selected_index = 0;
+            }
+            4 => return Ok(false),
             _ => {}
         },
         // Game, Time, and Weather
@@ -156,29 +592,43 @@ fn select(
             }
             2 => {
                 managers.state_manager.current_state = super::states::StateType::TravelTown;
+                ui_components.menu.selected_index = 0;
             }
             3 => {
                 managers.state_manager.current_state = super::states::StateType::TravelBuilding;
+                ui_components.menu.selected_index = 0;
             }
             4 => {
-                match managers.save_manager.save(
-                    &managers.world_manager,
-                    &managers.time_manager,
-                    &managers.weather_manager,
-                ) {
-                    Ok(()) => {
-                        managers.state_manager.current_state =
-                            super::states::StateType::GameSaveSuccess;
-                    }
-                    Err(e) => {
-                        log::error!("Failed to save game: {}", e);
-
-                        managers.state_manager.current_state =
-                            super::states::StateType::GameSaveError;
-                    }
-                };
+                managers.state_manager.back_state = super::states::StateType::Game;
+                managers.state_manager.current_state = super::states::StateType::Map;
+                ui_components.menu.selected_index = 0;
             }
             5 => {
+                managers.state_manager.current_state = super::states::StateType::Inventory;
+                ui_components.menu.selected_index = 0;
+            }
+            6 => {
+                managers.state_manager.current_state = super::states::StateType::Character;
+                ui_components.menu.selected_index = 0;
+            }
+            7 => {
+                managers.state_manager.current_state = super::states::StateType::Journal;
+                ui_components.menu.selected_index = 0;
+            }
+            8 => {
+                managers.state_manager.current_state = super::states::StateType::Directory;
+                ui_components.menu.selected_index = 0;
+            }
+            9 => {
+                managers.state_manager.current_state = super::states::StateType::Records;
+                ui_components.menu.selected_index = 0;
+            }
+            10 => {
+                managers.state_manager.back_state = super::states::StateType::Game;
+                save_game(managers);
+            }
+            11 => {
+                managers.state_manager.back_state = super::states::StateType::Game;
                 managers.state_manager.current_state = super::states::StateType::GameQuit;
                 ui_components.menu.selected_index = 0;
             }
@@ -189,12 +639,13 @@ fn select(
             0 => {
                 managers.time_manager.stop();
                 managers.weather_manager.stop();
+                managers.records_manager.flush();
 
                 managers.state_manager.current_state = crate::core::states::StateType::MainMenu;
                 ui_components.menu.selected_index = 0;
             }
             1 => {
-                managers.state_manager.current_state = crate::core::states::StateType::Game;
+                managers.state_manager.current_state = managers.state_manager.back_state.clone();
                 ui_components.menu.selected_index = 0;
             }
             _ => {}
@@ -209,86 +660,12 @@ fn select(
                 if selected_option == "Back" {
                     managers.state_manager.current_state = super::states::StateType::Game;
                     ui_components.menu.selected_index = 0;
-                } else if let Some(player) = managers.world_manager.player.as_mut() {
-                    // Save current town
-                    let current_town = if let Some(world) = managers.world_manager.world.as_ref() {
-                        if let Some(town) = world.towns.get(&player.current_town_id) {
-                            town.name.clone()
-                        } else {
-                            log::error!(
-                                "Failed to find town name for town ID: {}",
-                                player.current_town_id
-                            );
-                            panic!(
-                                "Failed to find town name for town ID: {}",
-                                player.current_town_id
-                            )
-                        }
-                    } else {
-                        log::error!(
-                            "Failed to find town name for town ID: {}",
-                            player.current_town_id
-                        );
-                        panic!(
-                            "Failed to find town name for town ID: {}",
-                            player.current_town_id
-                        )
-                    };
-
-                    // Change town to new town
-                    let towns = if let Some(world) = managers.world_manager.world.as_ref() {
-                        &world.towns
-                    } else {
-                        log::error!("Failed to find world data while attempting to travel.");
-                        panic!("Failed to find world data while attempting to travel.")
-                    };
-
-                    match find_id_by_name(towns, selected_option) {
-                        Some(id) => player.current_town_id = id,
-                        None => {
-                            log::error!(
-                                "Failed to find town ID for town name: {}",
-                                selected_option
-                            );
-                            panic!("Failed to find town ID for town name: {}", selected_option);
-                        }
-                    };
-
-                    // Move player to outside
-                    player.current_building_id = None;
-
-                    // Stop time
-                    managers.time_manager.stop();
-
-                    // Save time
-                    let mut time = managers
-                        .time_manager
-                        .time_arc_rwlock
-                        .as_ref()
-                        .and_then(|game_time| game_time.read().ok().map(|t| t.clone()));
-
-                    // Advance and restart time
-                    match time.as_mut() {
-                        Some(time_unwrapped) => {
-                            let travel_time = managers
-                                .world_manager
-                                .get_travel_time(&current_town, selected_option);
-
-                            time_unwrapped.tick += travel_time;
-                            time_unwrapped.day += time_unwrapped.tick / 900;
-                            time_unwrapped.tick %= 900;
-
-                            managers.time_manager.start(time_unwrapped.clone());
-                        }
-                        None => {
-                            managers
-                                .time_manager
-                                .start(crate::world::time::GameTime::new());
-                        }
-                    }
+                } else {
+                    let destination = selected_option
+                        .trim_end_matches(crate::ui::menu::UNVISITED_MARKER)
+                        .to_string();
 
-                    managers.state_manager.current_state = super::states::StateType::Game;
-                    ui_components.menu.selected_index = 0;
+                    walk_to_town(managers, ui_components, &destination);
                 }
             }
             None => {
@@ -298,6 +675,18 @@ fn select(
                 );
             }
         },
+        // Travel Confirmation
+        super::states::StateType::TravelConfirm => {
+            let destination = managers.state_manager.selected_town.take();
+
+            match (ui_components.menu.selected_index, destination) {
+                (0, Some(destination)) => walk_to_town(managers, ui_components, &destination),
+                _ => {
+                    managers.state_manager.current_state = super::states::StateType::Map;
+                    ui_components.menu.selected_index = 0;
+                }
+            }
+        }
         // Travel Building
         super::states::StateType::TravelBuilding => match ui_components
             .menu
@@ -313,17 +702,41 @@ fn select(
                     managers.state_manager.current_state = super::states::StateType::Game;
                     ui_components.menu.selected_index = 0;
                 } else if let Some(player) = managers.world_manager.player.as_mut() {
-                    let buildings = if let Some(world) = managers.world_manager.world.as_ref() {
-                        &world.buildings
+                    // Building names aren't unique across towns, so look up by position in the current town
+                    let town = if let Some(world) = managers.world_manager.world.as_ref() {
+                        world.towns.get(&player.current_town_id)
                     } else {
                         log::error!("Failed to find world data while attempting to travel.");
                         panic!("Failed to find world data while attempting to travel.")
                     };
 
-                    player.current_building_id = find_id_by_name(buildings, selected_option);
+                    player.current_building_id = town
+                        .and_then(|town| town.buildings.get(ui_components.menu.selected_index))
+                        .map(|building| building.id);
+                    let building_id = player.current_building_id;
 
                     managers.state_manager.current_state = super::states::StateType::Building;
                     ui_components.menu.selected_index = 0;
+
+                    if let Some(building_id) = building_id {
+                        // Somewhere new is worth a little experience
+                        let explored = player.visited_buildings.insert(building_id);
+                        let level_note = if explored {
+                            player.gain_experience(EXPLORE_EXPERIENCE)
+                        } else {
+                            None
+                        };
+
+                        let mut notes = managers.quest_manager.handle(
+                            crate::world::quest::QuestEvent::EnterBuilding(building_id),
+                            &mut managers.world_manager,
+                        );
+                        notes.extend(level_note);
+
+                        if !notes.is_empty() {
+                            show_notice(managers, ui_components, notes.join("\n"));
+                        }
+                    }
                 }
             }
             None => {
@@ -338,6 +751,7 @@ fn select(
             .menu
             .menu_options
             .get(ui_components.menu.selected_index)
+            .map(|option| option.trim_end_matches(crate::ui::menu::UNVISITED_MARKER))
         {
             Some(selected_option) => {
                 if selected_option == "Back" {
@@ -347,6 +761,37 @@ fn select(
 
                     managers.state_manager.current_state = super::states::StateType::TravelBuilding;
                     ui_components.menu.selected_index = 0;
+                } else if selected_option == "Trade" {
+                    // Restock the shop if the day has rolled over since it was last stocked
+                    let building_id = managers
+                        .world_manager
+                        .player
+                        .as_ref()
+                        .and_then(|player| player.current_building_id);
+                    let day = managers
+                        .time_manager
+                        .now()
+                        .map(|time| time.day)
+                        .unwrap_or(1);
+
+                    if let Some(building_id) = building_id {
+                        managers.world_manager.restock_shop(
+                            building_id,
+                            day,
+                            &managers.rng_manager,
+                        );
+                    }
+
+                    managers.state_manager.current_state = super::states::StateType::Shop;
+                    ui_components.menu.selected_index = 0;
+                } else if selected_option == "Tavern Services" {
+                    managers.state_manager.current_state = super::states::StateType::Tavern;
+                    ui_components.menu.selected_index = 0;
+                } else if selected_option == "Temple Services" {
+                    managers.state_manager.current_state = super::states::StateType::Temple;
+                    ui_components.menu.selected_index = 0;
+                } else if let Some(message) = room_refusal(managers, selected_option) {
+                    show_notice(managers, ui_components, message);
                 } else if let Some(player) = managers.world_manager.player.as_mut() {
                     match selected_option.parse::<u32>() {
                         Ok(id) => player.current_room_id = Some(id),
@@ -362,9 +807,25 @@ fn select(
                             );
                         }
                     }
+                    let room_id = player.current_room_id;
+
+                    if let Some(room_id) = room_id {
+                        player.visited_rooms.insert(room_id);
+                    }
 
                     managers.state_manager.current_state = super::states::StateType::Room;
                     ui_components.menu.selected_index = 0;
+
+                    if let Some(room_id) = room_id {
+                        let notes = managers.quest_manager.handle(
+                            crate::world::quest::QuestEvent::EnterRoom(room_id),
+                            &mut managers.world_manager,
+                        );
+
+                        if !notes.is_empty() {
+                            show_notice(managers, ui_components, notes.join("\n"));
+                        }
+                    }
                 }
             }
             None => {
@@ -375,21 +836,1945 @@ fn select(
             }
         },
         // Room
-        super::states::StateType::Room => {
-            if ui_components.menu.selected_index == 0 {
-                if let Some(player) = managers.world_manager.player.as_mut() {
-                    player.current_room_id = None;
-                }
+        super::states::StateType::Room => match ui_components
+            .menu
+            .menu_options
+            .get(ui_components.menu.selected_index)
+        {
+            Some(selected_option) => {
+                if selected_option == "Back" {
+                    if let Some(player) = managers.world_manager.player.as_mut() {
+                        player.current_room_id = None;
+                    }
 
-                managers.state_manager.current_state = crate::core::states::StateType::Building;
-                ui_components.menu.selected_index = 0;
-            }
-        }
-        // All other states
-        _ => {}
+                    managers.state_manager.current_state = crate::core::states::StateType::Building;
+                    ui_components.menu.selected_index = 0;
+                } else if let Some(npc_id) =
+                    selected_room_npc(managers, ui_components.menu.selected_index)
+                {
+                    managers.state_manager.selected_npc_id = Some(npc_id);
+
+                    managers.state_manager.current_state = super::states::StateType::Npc;
+                    ui_components.menu.selected_index = 0;
+                } else {
+                    // Containers are listed in the same order as the room's containers
+                    let container_id = managers
+                        .world_manager
+                        .player
+                        .as_ref()
+                        .and_then(|player| player.current_room_id)
+                        .and_then(|room_id| {
+                            managers
+                                .world_manager
+                                .world
+                                .as_ref()
+                                .and_then(|world| world.rooms.get(&room_id))
+                        })
+                        .and_then(|room| room.containers.get(ui_components.menu.selected_index))
+                        .map(|container| container.id);
+
+                    match container_id {
+                        Some(container_id)
+                            if managers.world_manager.is_container_locked(container_id) =>
+                        {
+                            managers.state_manager.selected_container_id = Some(container_id);
+
+                            managers.state_manager.current_state =
+                                super::states::StateType::Lockpick;
+                            ui_components.menu.selected_index = 0;
+                        }
+                        Some(container_id) => {
+                            if managers
+                                .world_manager
+                                .open_container(container_id, &managers.rng_manager)
+                                .is_some()
+                            {
+                                managers.records_manager.open_container(container_id);
+                                managers.state_manager.selected_container_id = Some(container_id);
+
+                                managers.state_manager.current_state =
+                                    super::states::StateType::Container;
+                                ui_components.menu.selected_index = 0;
+                            } else {
+                                log::error!(
+                                    "Failed to open container for container ID: {}",
+                                    container_id
+                                );
+                            }
+                        }
+                        None => {
+                            log::error!(
+                                "Failed to find container at selected index {}",
+                                ui_components.menu.selected_index
+                            );
+                        }
+                    }
+                }
+            }
+            None => {
+                log::error!(
+                    "Failed to find container at selected index {}",
+                    ui_components.menu.selected_index
+                );
+            }
+        },
+        // Container
+        super::states::StateType::Container => match ui_components
+            .menu
+            .menu_options
+            .get(ui_components.menu.selected_index)
+        {
+            Some(selected_option) => {
+                if selected_option == "Back" {
+                    managers.state_manager.selected_container_id = None;
+
+                    managers.state_manager.current_state = super::states::StateType::Room;
+                    ui_components.menu.selected_index = 0;
+                } else {
+                    let (mut message, taken) = if selected_option == "Take All" {
+                        take_all_items(managers)
+                    } else {
+                        take_item(managers, ui_components.menu.selected_index)
+                    };
+
+                    if let Some(container_id) = managers.state_manager.selected_container_id {
+                        for note in managers.quest_manager.handle(
+                            crate::world::quest::QuestEvent::TakeItem(container_id, taken),
+                            &mut managers.world_manager,
+                        ) {
+                            message.push('\n');
+                            message.push_str(&note);
+                        }
+                    }
+
+                    show_notice(managers, ui_components, message);
+                }
+            }
+            None => {
+                log::error!(
+                    "Failed to find item at selected index {}",
+                    ui_components.menu.selected_index
+                );
+            }
+        },
+        // Lockpick
+        super::states::StateType::Lockpick => match ui_components.menu.selected_index {
+            0 => pick_lock(managers, ui_components),
+            1 => {
+                managers.state_manager.selected_container_id = None;
+
+                managers.state_manager.current_state = super::states::StateType::Room;
+                ui_components.menu.selected_index = 0;
+            }
+            _ => {}
+        },
+        // Shop
+        super::states::StateType::Shop => match ui_components
+            .menu
+            .menu_options
+            .get(ui_components.menu.selected_index)
+        {
+            Some(selected_option) => {
+                if selected_option == "Back" {
+                    managers.state_manager.current_state = super::states::StateType::Building;
+                    ui_components.menu.selected_index = 0;
+                } else if selected_option == "Buy" {
+                    managers.state_manager.current_state = super::states::StateType::ShopBuy;
+                    ui_components.menu.selected_index = 0;
+                } else if selected_option == "Sell" {
+                    managers.state_manager.current_state = super::states::StateType::ShopSell;
+                    ui_components.menu.selected_index = 0;
+                } else if selected_option.starts_with("Pay Bounty") {
+                    let message = pay_bounty(managers);
+                    show_notice(managers, ui_components, message);
+                }
+            }
+            None => {
+                log::error!(
+                    "Failed to find option at selected index {}",
+                    ui_components.menu.selected_index
+                );
+            }
+        },
+        // Shop (Buy) and Shop (Sell)
+        super::states::StateType::ShopBuy | super::states::StateType::ShopSell => {
+            match ui_components
+                .menu
+                .menu_options
+                .get(ui_components.menu.selected_index)
+            {
+                Some(selected_option) => {
+                    if selected_option == "Back" {
+                        managers.state_manager.current_state = super::states::StateType::Shop;
+                        ui_components.menu.selected_index = 0;
+                    } else {
+                        let message = match managers.state_manager.current_state {
+                            super::states::StateType::ShopBuy => {
+                                buy_item(managers, ui_components.menu.selected_index)
+                            }
+                            _ => sell_item(managers, ui_components.menu.selected_index),
+                        };
+                        show_notice(managers, ui_components, message);
+                    }
+                }
+                None => {
+                    log::error!(
+                        "Failed to find item at selected index {}",
+                        ui_components.menu.selected_index
+                    );
+                }
+            }
+        }
+        // Tavern
+        super::states::StateType::Tavern => match ui_components.menu.selected_index {
+            0 => {
+                let message = rest_until_dawn(managers);
+                show_notice(managers, ui_components, message);
+            }
+            1 => {
+                let message = eat_hot_meal(managers);
+                show_notice(managers, ui_components, message);
+            }
+            2 => {
+                let message = buy_from_tavern(managers, crate::entities::item::ITEM_BREAD);
+                show_notice(managers, ui_components, message);
+            }
+            3 => {
+                let message = buy_from_tavern(managers, crate::entities::item::ITEM_ALE);
+                show_notice(managers, ui_components, message);
+            }
+            4 => {
+                let message = hear_rumour(managers);
+                show_notice(managers, ui_components, message);
+            }
+            5 => {
+                // Pin up fresh requests if the day has rolled over since the board was last checked
+                let building_id = managers
+                    .world_manager
+                    .player
+                    .as_ref()
+                    .and_then(|player| player.current_building_id);
+                let day = managers
+                    .time_manager
+                    .now()
+                    .map(|time| time.day)
+                    .unwrap_or(1);
+
+                if let Some(building_id) = building_id {
+                    managers.world_manager.refresh_quest_board(
+                        building_id,
+                        day,
+                        &managers.rng_manager,
+                    );
+                }
+
+                managers.state_manager.current_state = super::states::StateType::TavernBoard;
+                ui_components.menu.selected_index = 0;
+            }
+            6 => {
+                managers.state_manager.current_state = super::states::StateType::TavernCoach;
+                ui_components.menu.selected_index = 0;
+            }
+            7 => {
+                managers.state_manager.current_state = super::states::StateType::Building;
+                ui_components.menu.selected_index = 0;
+            }
+            _ => {}
+        },
+        // Tavern (Notice Board)
+        super::states::StateType::TavernBoard => match ui_components
+            .menu
+            .menu_options
+            .get(ui_components.menu.selected_index)
+        {
+            Some(selected_option) => {
+                if selected_option == "Back" {
+                    managers.state_manager.current_state = super::states::StateType::Tavern;
+                    ui_components.menu.selected_index = 0;
+                } else {
+                    let message = take_board_quest(managers, ui_components.menu.selected_index);
+                    show_notice(managers, ui_components, message);
+                }
+            }
+            None => {
+                log::error!(
+                    "Failed to find quest at selected index {}",
+                    ui_components.menu.selected_index
+                );
+            }
+        },
+        // Tavern (Coach)
+        super::states::StateType::TavernCoach => match ui_components
+            .menu
+            .menu_options
+            .get(ui_components.menu.selected_index)
+        {
+            Some(selected_option) => {
+                if selected_option == "Back" {
+                    managers.state_manager.current_state = super::states::StateType::Tavern;
+                    ui_components.menu.selected_index = 0;
+                } else {
+                    book_coach(managers, ui_components);
+                }
+            }
+            None => {
+                log::error!(
+                    "Failed to find town at selected index {}",
+                    ui_components.menu.selected_index
+                );
+            }
+        },
+        // Directory
+        super::states::StateType::Directory => match ui_components
+            .menu
+            .menu_options
+            .get(ui_components.menu.selected_index)
+        {
+            Some(selected_option) => {
+                if selected_option == "Back" {
+                    managers.state_manager.search_query.clear();
+
+                    managers.state_manager.current_state = super::states::StateType::Game;
+                    ui_components.menu.selected_index = 0;
+                } else {
+                    // NPCs are listed in the same order as the search results
+                    let npc_id = managers
+                        .world_manager
+                        .search_npcs(&managers.state_manager.search_query)
+                        .get(ui_components.menu.selected_index)
+                        .map(|npc| npc.id);
+
+                    match npc_id {
+                        Some(npc_id) => {
+                            managers.state_manager.selected_npc_id = Some(npc_id);
+
+                            managers.state_manager.current_state =
+                                super::states::StateType::DirectoryNpc;
+                            ui_components.menu.selected_index = 0;
+                        }
+                        None => {
+                            log::error!(
+                                "Failed to find NPC at selected index {}",
+                                ui_components.menu.selected_index
+                            );
+                        }
+                    }
+                }
+            }
+            None => {
+                log::error!(
+                    "Failed to find NPC at selected index {}",
+                    ui_components.menu.selected_index
+                );
+            }
+        },
+        // Directory (NPC)
+        super::states::StateType::DirectoryNpc => match ui_components.menu.selected_index {
+            0 => {
+                managers.state_manager.plotted_route = Some(plot_route(managers));
+            }
+            1 => {
+                managers.state_manager.selected_npc_id = None;
+                managers.state_manager.plotted_route = None;
+
+                managers.state_manager.current_state = super::states::StateType::Directory;
+                ui_components.menu.selected_index = 0;
+            }
+            _ => {}
+        },
+        // NPC
+        super::states::StateType::Npc => match ui_components.menu.selected_index {
+            0 => match managers.state_manager.selected_npc_id.and_then(|npc_id| {
+                crate::world::dialogue::DialogueContext::new(
+                    &managers.world_manager,
+                    &managers.time_manager,
+                    &managers.weather_manager,
+                    npc_id,
+                )
+            }) {
+                Some(context) => {
+                    let npc_id = context.npc.id;
+                    managers
+                        .dialogue_manager
+                        .start(&context, &managers.rng_manager);
+                    managers.records_manager.meet_npc(npc_id);
+
+                    // Anything the player was asked to bring or tell them comes up first
+                    let notes = managers.quest_manager.handle(
+                        crate::world::quest::QuestEvent::Talk(npc_id),
+                        &mut managers.world_manager,
+                    );
+
+                    if let Some(conversation) = managers.dialogue_manager.conversation.as_mut() {
+                        conversation.notes = notes;
+                    }
+
+                    managers.state_manager.current_state = super::states::StateType::Dialogue;
+                    ui_components.menu.selected_index = 0;
+                }
+                None => {
+                    log::error!(
+                        "Failed to find NPC for NPC ID: {:?}",
+                        managers.state_manager.selected_npc_id
+                    );
+                }
+            },
+            1 => {
+                managers.state_manager.selected_npc_id = None;
+
+                managers.state_manager.current_state = super::states::StateType::Room;
+                ui_components.menu.selected_index = 0;
+            }
+            _ => {}
+        },
+        // Dialogue
+        super::states::StateType::Dialogue => {
+            let effects = match managers.state_manager.selected_npc_id.and_then(|npc_id| {
+                crate::world::dialogue::DialogueContext::new(
+                    &managers.world_manager,
+                    &managers.time_manager,
+                    &managers.weather_manager,
+                    npc_id,
+                )
+            }) {
+                Some(context) => managers
+                    .dialogue_manager
+                    .choose(ui_components.menu.selected_index, &context),
+                None => None,
+            };
+
+            // No response means "Goodbye" was chosen
+            let chosen = effects.is_some();
+            let notes = match effects {
+                Some(effects) => apply_effects(managers, effects),
+                None => Vec::new(),
+            };
+
+            let finished = match managers.dialogue_manager.conversation.as_mut() {
+                Some(conversation) if chosen && !conversation.is_finished() => {
+                    conversation.notes = notes.clone();
+                    false
+                }
+                _ => true,
+            };
+
+            ui_components.menu.selected_index = 0;
+
+            if finished {
+                managers.dialogue_manager.end();
+
+                managers.state_manager.current_state = super::states::StateType::Npc;
+                ui_components.menu.selected_index = 0;
+
+                if !notes.is_empty() {
+                    show_notice(managers, ui_components, notes.join("\n"));
+                }
+            }
+        }
+        // Temple
+        super::states::StateType::Temple => match ui_components.menu.selected_index {
+            0 => {
+                let message = heal_wounds(managers);
+                show_notice(managers, ui_components, message);
+            }
+            1 => {
+                let message = cure_ailments(managers);
+                show_notice(managers, ui_components, message);
+            }
+            2 => {
+                managers.state_manager.current_state = super::states::StateType::TempleBlessing;
+                ui_components.menu.selected_index = 0;
+            }
+            3 => {
+                let message = donate(managers);
+                show_notice(managers, ui_components, message);
+            }
+            4 => {
+                let message = set_return_point(managers);
+                show_notice(managers, ui_components, message);
+            }
+            5 => {
+                managers.state_manager.current_state = super::states::StateType::Building;
+                ui_components.menu.selected_index = 0;
+            }
+            _ => {}
+        },
+        // Temple (Blessing)
+        super::states::StateType::TempleBlessing => match ui_components
+            .menu
+            .menu_options
+            .get(ui_components.menu.selected_index)
+        {
+            Some(selected_option) => {
+                if selected_option == "Back" {
+                    managers.state_manager.current_state = super::states::StateType::Temple;
+                    ui_components.menu.selected_index = 0;
+                } else {
+                    let message = receive_blessing(managers, ui_components.menu.selected_index);
+                    show_notice(managers, ui_components, message);
+                }
+            }
+            None => {
+                log::error!(
+                    "Failed to find blessing at selected index {}",
+                    ui_components.menu.selected_index
+                );
+            }
+        },
+        // Inventory
+        super::states::StateType::Inventory => match ui_components
+            .menu
+            .menu_options
+            .get(ui_components.menu.selected_index)
+        {
+            Some(selected_option) => {
+                if selected_option == "Back" {
+                    managers.state_manager.current_state = super::states::StateType::Game;
+                    ui_components.menu.selected_index = 0;
+                } else {
+                    managers.state_manager.selected_item_index =
+                        Some(ui_components.menu.selected_index);
+
+                    managers.state_manager.current_state = super::states::StateType::InventoryItem;
+                    ui_components.menu.selected_index = 0;
+                }
+            }
+            None => {
+                log::error!(
+                    "Failed to find item at selected index {}",
+                    ui_components.menu.selected_index
+                );
+            }
+        },
+        // Journal
+        super::states::StateType::Journal => match ui_components
+            .menu
+            .menu_options
+            .get(ui_components.menu.selected_index)
+        {
+            Some(selected_option) => {
+                if selected_option == "Back" {
+                    managers.state_manager.current_state = super::states::StateType::Game;
+                    ui_components.menu.selected_index = 0;
+                } else {
+                    managers.state_manager.selected_quest_index =
+                        Some(ui_components.menu.selected_index);
+
+                    managers.state_manager.current_state = super::states::StateType::JournalQuest;
+                    ui_components.menu.selected_index = 0;
+                }
+            }
+            None => {
+                log::error!(
+                    "Failed to find quest at selected index {}",
+                    ui_components.menu.selected_index
+                );
+            }
+        },
+        // Journal (Quest)
+        super::states::StateType::JournalQuest => {
+            managers.state_manager.selected_quest_index = None;
+
+            managers.state_manager.current_state = super::states::StateType::Journal;
+            ui_components.menu.selected_index = 0;
+        }
+        // Map
+        super::states::StateType::Map => {
+            managers.state_manager.current_state = managers.state_manager.back_state.clone();
+            ui_components.menu.selected_index = 0;
+        }
+        // Records
+        super::states::StateType::Records => {
+            managers.state_manager.current_state = super::states::StateType::Game;
+            ui_components.menu.selected_index = 0;
+        }
+        // Keybindings
+        super::states::StateType::Keybindings => {
+            match crate::core::keymap::Preset::iter().nth(ui_components.menu.selected_index) {
+                Some(preset) => managers.config_manager.set_preset(preset),
+                None => {
+                    managers.state_manager.current_state =
+                        managers.state_manager.back_state.clone();
+                    ui_components.menu.selected_index = 0;
+                }
+            }
+        }
+        // Themes
+        super::states::StateType::Themes => {
+            match crate::ui::theme::Palette::iter().nth(ui_components.menu.selected_index) {
+                Some(palette) => managers.config_manager.set_palette(palette),
+                None => {
+                    managers.state_manager.current_state = super::states::StateType::MainMenu;
+                    ui_components.menu.selected_index = 0;
+                }
+            }
+        }
+        // Character
+        super::states::StateType::Character => {
+            let attributes = crate::entities::attributes::Attribute::ALL.len();
+            let skills = crate::entities::progression::Skill::ALL.len();
+            let index = ui_components.menu.selected_index;
+
+            if index < attributes {
+                let message = raise_attribute(managers, index);
+                show_notice(managers, ui_components, message);
+            } else if index < attributes + skills {
+                let message = learn_skill(managers, index - attributes);
+                show_notice(managers, ui_components, message);
+            } else {
+                managers.state_manager.current_state = super::states::StateType::Game;
+                ui_components.menu.selected_index = 0;
+            }
+        }
+        // Inventory Item
+        super::states::StateType::InventoryItem => match ui_components.menu.selected_index {
+            0 => {
+                let message = use_item(managers);
+                show_notice(managers, ui_components, message);
+            }
+            1 => {
+                let message = drop_item(managers);
+                show_notice(managers, ui_components, message);
+            }
+            2 => {
+                managers.state_manager.selected_item_index = None;
+
+                managers.state_manager.current_state = super::states::StateType::Inventory;
+                ui_components.menu.selected_index = 0;
+            }
+            _ => {}
+        },
+        // All other states
+        _ => {}
+    }
+
+    Ok(true)
+}
+
+// Show a notice popup, Enter returns to the current state
+fn show_notice(
+    managers: &mut crate::ui::display::Managers,
+    ui_components: &mut crate::ui::display::UIComponents,
+    message: String,
+) {
+    record_message(managers, &message);
+    ui_components.popup.message = message;
+
+    managers.state_manager.return_state = managers.state_manager.current_state.clone();
+    managers.state_manager.current_state = super::states::StateType::Notice;
+}
+
+// Add a message to the log, stamped with the current in-game time
+fn record_message(managers: &mut crate::ui::display::Managers, text: &str) {
+    let time = managers.time_manager.now();
+    managers.message_manager.record(time, text);
+}
+
+// Show a notice for an achievement earned since the last one was announced, waits for any notice
+// already showing and stays quiet outside of a game
+pub fn announce_achievement(
+    managers: &mut crate::ui::display::Managers,
+    ui_components: &mut crate::ui::display::UIComponents,
+) {
+    if managers.world_manager.player.is_none()
+        || matches!(
+            managers.state_manager.current_state,
+            super::states::StateType::Notice
+                | super::states::StateType::MainMenu
+                | super::states::StateType::Name
+                | super::states::StateType::NameConfirm
+                | super::states::StateType::GameQuit
+        )
+    {
+        return;
+    }
+
+    if let Some(message) = managers.records_manager.take_unlocked() {
+        show_notice(managers, ui_components, message);
+    }
+}
+
+// Attempt to pick the lock on the selected container
+fn pick_lock(
+    managers: &mut crate::ui::display::Managers,
+    ui_components: &mut crate::ui::display::UIComponents,
+) {
+    let container_id = match managers.state_manager.selected_container_id {
+        Some(container_id) => container_id,
+        None => return,
+    };
+
+    let (difficulty, container_type) = match managers
+        .world_manager
+        .world
+        .as_ref()
+        .and_then(|world| world.containers.get(&container_id))
+    {
+        Some(container) => (container.lock_difficulty, container.container_type.clone()),
+        None => {
+            log::error!(
+                "Failed to find container for container ID: {}",
+                container_id
+            );
+            return;
+        }
+    };
+
+    let lockpicks = managers
+        .world_manager
+        .player
+        .as_ref()
+        .map(|player| player.inventory.count(crate::entities::item::ITEM_LOCKPICK))
+        .unwrap_or(0);
+
+    if lockpicks == 0 {
+        show_notice(
+            managers,
+            ui_components,
+            "You don't have any lockpicks.".into(),
+        );
+        return;
+    }
+
+    // Each attempt takes time
+    managers.time_manager.advance(LOCKPICK_TICKS);
+
+    // Anyone in the room might notice
+    let witness = managers
+        .world_manager
+        .player
+        .as_ref()
+        .and_then(|player| player.current_room_id)
+        .and_then(|room_id| {
+            managers
+                .world_manager
+                .world
+                .as_ref()
+                .and_then(|world| world.rooms.get(&room_id))
+        })
+        .and_then(|room| {
+            let npcs = managers
+                .world_manager
+                .npcs_in_room(room.id, &managers.time_manager.phase());
+            let chance = (npcs.len() as u32 * LOCKPICK_WITNESS_CHANCE).min(75);
+
+            if !npcs.is_empty() && managers.rng_manager.roll_dice(100) <= chance {
+                Some(npcs[managers.rng_manager.pick_index(npcs.len())].clone())
+            } else {
+                None
+            }
+        });
+
+    if let Some(witness) = witness {
+        let bounty = LOCKPICK_BOUNTY * difficulty;
+
+        if let Some(player) = managers.world_manager.player.as_mut() {
+            // Lockpicks are confiscated and a bounty is placed in this town
+            player
+                .inventory
+                .remove_by_id(crate::entities::item::ITEM_LOCKPICK, lockpicks);
+            *player.bounties.entry(player.current_town_id).or_insert(0) += bounty;
+
+            // Word gets around
+            player.change_reputation(player.current_town_id, THEFT_REPUTATION);
+            player.change_disposition(witness.id, THEFT_DISPOSITION);
+
+            // Thrown out into the street
+            player.current_building_id = None;
+            player.current_room_id = None;
+        }
+
+        managers.state_manager.selected_container_id = None;
+        managers.state_manager.current_state = super::states::StateType::Game;
+        ui_components.menu.selected_index = 0;
+
+        show_notice(
+            managers,
+            ui_components,
+            format!(
+                "{} catches you picking the lock!\nYour lockpicks are taken and your name is mud.\nA bounty of {} gold is placed on your head.",
+                witness.name, bounty
+            ),
+        );
+        return;
+    }
+
+    // Roll against the lock, the Blessing of Fortune and lockpicking skill help
+    let day = managers
+        .time_manager
+        .now()
+        .map(|time| time.day)
+        .unwrap_or(1);
+    let bonus = match managers.world_manager.player.as_ref() {
+        Some(player) => {
+            let fortune = if player.is_blessed(&BlessingType::Fortune, day) {
+                FORTUNE_BONUS
+            } else {
+                0
+            };
+
+            fortune + player.progression.lockpicking * LOCKPICKING_BONUS
+        }
+        None => 0,
+    };
+
+    if managers.rng_manager.roll_dice(20) + bonus >= 8 + difficulty * 2 {
+        managers
+            .world_manager
+            .unlocked_containers
+            .insert(container_id);
+        managers
+            .world_manager
+            .open_container(container_id, &managers.rng_manager);
+        managers.records_manager.open_container(container_id);
+
+        managers.state_manager.current_state = super::states::StateType::Container;
+        ui_components.menu.selected_index = 0;
+
+        show_notice(
+            managers,
+            ui_components,
+            format!("The lock clicks and the {:?} opens.", container_type),
+        );
+    } else {
+        if let Some(player) = managers.world_manager.player.as_mut() {
+            player
+                .inventory
+                .remove_by_id(crate::entities::item::ITEM_LOCKPICK, 1);
+        }
+
+        show_notice(
+            managers,
+            ui_components,
+            "Your lockpick snaps in the lock.".into(),
+        );
+    }
+}
+
+// Buy one of the item at index from the current shop
+fn buy_item(managers: &mut crate::ui::display::Managers, index: usize) -> String {
+    let standing = managers.world_manager.standing();
+    let proprietor_id = current_proprietor_id(managers);
+
+    let (player, stock) = match managers.world_manager.player.as_mut().and_then(|player| {
+        player
+            .current_building_id
+            .and_then(|building_id| managers.world_manager.shop_stock.get_mut(&building_id))
+            .map(|stock| (player, stock))
+    }) {
+        Some((player, stock)) => (player, stock),
+        None => return "There's nothing to buy.".into(),
+    };
+
+    let item = match stock.items.get(index) {
+        Some(slot) => slot.item.clone(),
+        None => return "There's nothing to buy.".into(),
+    };
+
+    let price = crate::world::shop::buy_price(&item, player.current_town_id, standing);
+
+    if player.gold < price {
+        return format!("You can't afford the {}.", item.name);
+    }
+
+    if let Err(e) = player.inventory.add(item.clone(), 1) {
+        return e.to_string();
+    }
+
+    player.gold -= price;
+    managers.records_manager.spend(price);
+    warm_to_trade(player, proprietor_id);
+
+    if let Some(slot) = stock.items.get_mut(index) {
+        slot.quantity -= 1;
+
+        if slot.quantity == 0 {
+            stock.items.remove(index);
+        }
+    }
+
+    format!("You buy the {} for {} gold.", item.name, price)
+}
+
+// Sell one of the inventory item at index to the current shop
+fn sell_item(managers: &mut crate::ui::display::Managers, index: usize) -> String {
+    let standing = managers.world_manager.standing();
+    let proprietor_id = current_proprietor_id(managers);
+
+    let (player, stock) = match managers.world_manager.player.as_mut().and_then(|player| {
+        player
+            .current_building_id
+            .and_then(|building_id| managers.world_manager.shop_stock.get_mut(&building_id))
+            .map(|stock| (player, stock))
+    }) {
+        Some((player, stock)) => (player, stock),
+        None => return "There's nothing to sell.".into(),
+    };
+
+    let sold = match player.inventory.remove(index, 1) {
+        Some(slot) => slot,
+        None => return "There's nothing to sell.".into(),
+    };
+
+    let price = crate::world::shop::sell_price(&sold.item, player.current_town_id, standing);
+    player.gold += price;
+    warm_to_trade(player, proprietor_id);
+
+    // The shop puts what it buys on its shelves
+    if let Some(slot) = stock
+        .items
+        .iter_mut()
+        .find(|slot| slot.item.id == sold.item.id)
+    {
+        slot.quantity += 1;
+    } else {
+        stock.items.push(sold.clone());
+    }
+
+    format!("You sell the {} for {} gold.", sold.item.name, price)
+}
+
+// Check whether the player is turned away from a room, returns why if they are
+fn room_refusal(managers: &crate::ui::display::Managers, selected_option: &str) -> Option<String> {
+    let room_id = selected_option.parse::<u32>().ok()?;
+    let building_id = managers
+        .world_manager
+        .player
+        .as_ref()?
+        .current_building_id?;
+
+    if managers.world_manager.can_enter_room(building_id, room_id) {
+        return None;
+    }
+
+    let building_name = managers
+        .world_manager
+        .world
+        .as_ref()
+        .and_then(|world| world.buildings.get(&building_id))
+        .map(|building| building.name.clone())
+        .unwrap_or_default();
+
+    Some(format!(
+        "That room is private. Nobody at the {} knows you well enough to let you in.",
+        building_name
+    ))
+}
+
+// Get the ID of the proprietor of the current building
+fn current_proprietor_id(managers: &crate::ui::display::Managers) -> Option<u32> {
+    managers
+        .world_manager
+        .player
+        .as_ref()
+        .and_then(|player| player.current_building_id)
+        .and_then(|building_id| managers.world_manager.proprietor(building_id))
+        .map(|npc| npc.id)
+}
+
+// Regular custom warms a shopkeeper to the player, up to a point
+fn warm_to_trade(player: &mut crate::entities::player::Player, proprietor_id: Option<u32>) {
+    if let Some(npc_id) = proprietor_id {
+        if player.disposition(npc_id) < TRADE_DISPOSITION_LIMIT {
+            player.change_disposition(npc_id, 1);
+        }
+    }
+}
+
+// Pay off the bounty in the current town
+fn pay_bounty(managers: &mut crate::ui::display::Managers) -> String {
+    let player = match managers.world_manager.player.as_mut() {
+        Some(player) => player,
+        None => return "There's no bounty to pay.".into(),
+    };
+
+    let bounty = player
+        .bounties
+        .get(&player.current_town_id)
+        .copied()
+        .unwrap_or(0);
+
+    if bounty == 0 {
+        return "There's no bounty to pay.".into();
+    }
+
+    if player.gold < bounty {
+        return format!("You need {} gold to pay off your bounty.", bounty);
+    }
+
+    player.gold -= bounty;
+    player.bounties.remove(&player.current_town_id);
+
+    format!("You pay off your bounty of {} gold.", bounty)
+}
+
+// Pay for a bed and sleep until dawn
+fn rest_until_dawn(managers: &mut crate::ui::display::Managers) -> String {
+    let player = match managers.world_manager.player.as_mut() {
+        Some(player) => player,
+        None => return "There's nowhere to rest.".into(),
+    };
+
+    let price = crate::world::tavern::rest_price(player.current_town_id);
+
+    if player.gold < price {
+        return format!("A bed costs {} gold. You can't afford it.", price);
+    }
+
+    player.gold -= price;
+    managers.records_manager.spend(price);
+    player.condition.rest();
+
+    // Sleep through the rest of the day, dawn starts at the first tick
+    let tick = managers
+        .time_manager
+        .now()
+        .map(|time| time.tick)
+        .unwrap_or(0);
+    managers
+        .time_manager
+        .advance(crate::world::time::TICKS_PER_DAY - tick);
+
+    let (day, total_ticks) = managers
+        .time_manager
+        .now()
+        .map(|time| (time.day, time.total_ticks()))
+        .unwrap_or((1, 0));
+
+    // Any tiredness left is slept off
+    if let Some(player) = managers.world_manager.player.as_mut() {
+        player.condition.sleep(total_ticks);
+    }
+
+    format!(
+        "You pay {} gold for a bed and wake at dawn on day {}.",
+        price, day
+    )
+}
+
+// Pay for a hot meal, leaving the player fully fed
+fn eat_hot_meal(managers: &mut crate::ui::display::Managers) -> String {
+    let player = match managers.world_manager.player.as_mut() {
+        Some(player) => player,
+        None => return "There's nothing to eat.".into(),
+    };
+
+    let price = crate::world::tavern::meal_price(player.current_town_id);
+
+    if player.gold < price {
+        return format!("A hot meal costs {} gold. You can't afford it.", price);
+    }
+
+    player.gold -= price;
+    managers.records_manager.spend(price);
+    player.condition.eat(crate::entities::condition::MAX_NEED);
+
+    format!("You pay {} gold for a hot meal and eat your fill.", price)
+}
+
+// Buy one of an item over the bar
+fn buy_from_tavern(managers: &mut crate::ui::display::Managers, item_id: u32) -> String {
+    let standing = managers.world_manager.standing();
+
+    let player = match managers.world_manager.player.as_mut() {
+        Some(player) => player,
+        None => return "There's nothing to buy.".into(),
+    };
+
+    let item = match crate::entities::item::Item::from_id(item_id) {
+        Some(item) => item,
+        None => {
+            log::error!("Failed to find item for item ID: {}", item_id);
+            return "There's nothing to buy.".into();
+        }
+    };
+
+    let price = crate::world::shop::buy_price(&item, player.current_town_id, standing);
+
+    if player.gold < price {
+        return format!("You can't afford the {}.", item.name);
+    }
+
+    if let Err(e) = player.inventory.add(item.clone(), 1) {
+        return e.to_string();
+    }
+
+    player.gold -= price;
+    managers.records_manager.spend(price);
+
+    format!("You buy the {} for {} gold.", item.name, price)
+}
+
+// Take a quest down from the tavern notice board
+fn take_board_quest(managers: &mut crate::ui::display::Managers, index: usize) -> String {
+    let log = match managers
+        .world_manager
+        .player
+        .as_ref()
+        .and_then(|player| player.current_building_id)
+        .and_then(|building_id| managers.world_manager.quest_boards.get_mut(&building_id))
+    {
+        Some(board) if index < board.quests.len() => board.quests.remove(index),
+        _ => return "There's nothing on the board.".into(),
+    };
+
+    crate::world::quest::accept(log, &mut managers.world_manager, &managers.rng_manager).join("\n")
+}
+
+// Listen in on the tavern gossip
+fn hear_rumour(managers: &mut crate::ui::display::Managers) -> String {
+    let town_id = match managers.world_manager.player.as_ref() {
+        Some(player) => player.current_town_id,
+        None => return "Nobody has anything to say.".into(),
+    };
+
+    let day = managers
+        .time_manager
+        .now()
+        .map(|time| time.day)
+        .unwrap_or(1);
+
+    let forecast = managers
+        .weather_manager
+        .weather_arc_rwlock
+        .as_ref()
+        .and_then(|game_weather| game_weather.read().ok().map(|w| w.forecast.clone()));
+
+    crate::world::tavern::generate_rumour(
+        &mut managers.world_manager,
+        town_id,
+        day,
+        forecast,
+        &managers.rng_manager,
+    )
+}
+
+// Book a coach to the selected destination, the coach leaves straight away
+fn book_coach(
+    managers: &mut crate::ui::display::Managers,
+    ui_components: &mut crate::ui::display::UIComponents,
+) {
+    let town_id = match managers.world_manager.player.as_ref() {
+        Some(player) => player.current_town_id,
+        None => return,
+    };
+
+    let (destination, fare) =
+        match crate::world::tavern::coach_destinations(&managers.world_manager, town_id)
+            .get(ui_components.menu.selected_index)
+        {
+            Some(destination) => destination.clone(),
+            None => {
+                log::error!(
+                    "Failed to find coach destination at selected index {}",
+                    ui_components.menu.selected_index
+                );
+                return;
+            }
+        };
+
+    if let Some(player) = managers.world_manager.player.as_mut() {
+        if player.gold < fare {
+            show_notice(
+                managers,
+                ui_components,
+                format!(
+                    "The fare to {} is {} gold. You can't afford it.",
+                    destination, fare
+                ),
+            );
+            return;
+        }
+
+        player.gold -= fare;
+        managers.records_manager.spend(fare);
+        player.current_room_id = None;
+    }
+
+    let note = travel_to_town(managers, &destination, true);
+
+    managers.state_manager.current_state = super::states::StateType::Game;
+    ui_components.menu.selected_index = 0;
+
+    let mut message = format!(
+        "You pay the driver {} gold and ride the coach to {}.",
+        fare, destination
+    );
+
+    if let Some(note) = note {
+        message.push('\n');
+        message.push_str(&note);
+    }
+
+    show_notice(managers, ui_components, message);
+}
+
+// Plot a route from the player to the selected NPC, over the roads and into the building
+fn plot_route(managers: &crate::ui::display::Managers) -> String {
+    let (player, world, npc) = match (
+        managers.world_manager.player.as_ref(),
+        managers.world_manager.world.as_ref(),
+        managers.state_manager.selected_npc_id.and_then(|npc_id| {
+            managers
+                .world_manager
+                .world
+                .as_ref()
+                .and_then(|world| world.npcs.get(&npc_id))
+        }),
+    ) {
+        (Some(player), Some(world), Some(npc)) => (player, world, npc),
+        _ => return "Failed to plot a route.".into(),
+    };
+
+    let (building_id, room_id) =
+        crate::world::schedule::npc_location(world, npc, &managers.time_manager.phase());
+
+    let (building, town) = match world.buildings.get(&building_id).and_then(|building| {
+        world
+            .towns
+            .get(&building.town_id)
+            .map(|town| (building, town))
+    }) {
+        Some(found) => found,
+        None => return format!("Nobody knows where {} is.", npc.name),
+    };
+
+    let mut steps: Vec<String> = Vec::new();
+
+    if town.id != player.current_town_id {
+        let origin = match world.towns.get(&player.current_town_id) {
+            Some(town) => town.name.clone(),
+            None => return "Failed to plot a route.".into(),
+        };
+
+        match managers.world_manager.get_route(&origin, &town.name) {
+            Some((route, distance)) => {
+                // Towns along the way stay hidden until the player has been there
+                let stops: Vec<String> = route
+                    .iter()
+                    .map(|stop| {
+                        let discovered = find_id_by_name(&world.towns, stop)
+                            .map(|id| player.visited_towns.contains(&id))
+                            .unwrap_or(false);
+
+                        if discovered || *stop == town.name {
+                            stop.clone()
+                        } else {
+                            crate::ui::menu::UNDISCOVERED_TOWN.to_string()
+                        }
+                    })
+                    .collect();
+
+                steps.push(format!(
+                    "Travel {} ({} miles)",
+                    stops.join(" -> "),
+                    distance
+                ));
+            }
+            None => return format!("There's no road to {}.", town.name),
+        }
+    }
+
+    if player.current_building_id != Some(building_id) {
+        steps.push(format!("Go to {}", building.name));
+    }
+
+    if player.current_room_id != Some(room_id) {
+        steps.push(format!("Go to room {}", room_id));
+    }
+
+    if steps.is_empty() {
+        format!("{} is right here.", npc.name)
+    } else {
+        steps.join("\n")
+    }
+}
+
+// Get the ID of the NPC at a Room menu index, NPCs are listed after the containers
+fn selected_room_npc(managers: &crate::ui::display::Managers, index: usize) -> Option<u32> {
+    let room_id = managers.world_manager.player.as_ref()?.current_room_id?;
+    let room = managers.world_manager.world.as_ref()?.rooms.get(&room_id)?;
+
+    index
+        .checked_sub(room.containers.len())
+        .and_then(|npc_index| {
+            managers
+                .world_manager
+                .npcs_in_room(room.id, &managers.time_manager.phase())
+                .get(npc_index)
+                .map(|npc| npc.id)
+        })
+}
+
+// Apply the effects of a dialogue response, returns notes describing what happened
+fn apply_effects(
+    managers: &mut crate::ui::display::Managers,
+    effects: Vec<crate::world::dialogue::Effect>,
+) -> Vec<String> {
+    let mut notes: Vec<String> = Vec::new();
+
+    let npc_id = match managers.state_manager.selected_npc_id {
+        Some(npc_id) => npc_id,
+        None => return notes,
+    };
+    let npc_town_id = managers
+        .world_manager
+        .world
+        .as_ref()
+        .and_then(|world| world.npcs.get(&npc_id))
+        .map(|npc| npc.town_id);
+
+    let player = match managers.world_manager.player.as_mut() {
+        Some(player) => player,
+        None => return notes,
+    };
+
+    // Quests are started after the other effects, as they need the whole World Manager
+    let mut new_quests: Vec<String> = Vec::new();
+    let mut offer_quest = false;
+
+    for effect in effects {
+        match effect {
+            crate::world::dialogue::Effect::GiveItem { item_id, quantity } => {
+                match crate::entities::item::Item::from_id(item_id) {
+                    Some(item) => {
+                        let slot = crate::entities::inventory::InventorySlot {
+                            item: item.clone(),
+                            quantity,
+                        };
+
+                        match player.inventory.add(item, quantity) {
+                            Ok(()) => notes.push(format!("You receive {}.", slot.label())),
+                            Err(e) => notes.push(e.to_string()),
+                        }
+                    }
+                    None => log::error!("Failed to find item for item ID: {}", item_id),
+                }
+            }
+            crate::world::dialogue::Effect::TakeGold(gold) => {
+                let gold = gold.min(player.gold);
+                player.gold -= gold;
+                notes.push(format!("You hand over {} gold.", gold));
+            }
+            crate::world::dialogue::Effect::StartQuest(quest_id) => {
+                if player.quest_status(&quest_id) == QuestStatus::NotStarted {
+                    new_quests.push(quest_id);
+                }
+            }
+            crate::world::dialogue::Effect::OfferQuest => {
+                offer_quest = true;
+            }
+            crate::world::dialogue::Effect::Reputation(amount) => {
+                if let Some(town_id) = npc_town_id {
+                    player.change_reputation(town_id, amount);
+
+                    if amount > 0 {
+                        notes.push("Your standing in town improves.".into());
+                    } else if amount < 0 {
+                        notes.push("Your standing in town suffers.".into());
+                    }
+                }
+            }
+            crate::world::dialogue::Effect::Disposition(amount) => {
+                player.change_disposition(npc_id, amount);
+
+                if amount > 0 {
+                    notes.push("They seem to warm to you.".into());
+                } else if amount < 0 {
+                    notes.push("They seem to cool towards you.".into());
+                }
+            }
+            crate::world::dialogue::Effect::LearnFact(fact) => {
+                player.learn_fact(npc_id, fact);
+            }
+        }
     }
 
-    Ok(true)
+    for quest_id in new_quests {
+        notes.extend(managers.quest_manager.start(
+            &quest_id,
+            npc_id,
+            &mut managers.world_manager,
+            &managers.rng_manager,
+        ));
+    }
+
+    if offer_quest {
+        let day = managers
+            .time_manager
+            .now()
+            .map(|time| time.day)
+            .unwrap_or(1);
+
+        match npc_town_id.and_then(|town_id| {
+            crate::world::quest::generate_quest(
+                &managers.world_manager,
+                town_id,
+                Some(npc_id),
+                day,
+                &managers.rng_manager,
+            )
+        }) {
+            Some(log) => {
+                let description = log.description.clone();
+                notes.extend(crate::world::quest::accept(
+                    log,
+                    &mut managers.world_manager,
+                    &managers.rng_manager,
+                ));
+                notes.push(description);
+            }
+            None => notes.push("They can't think of anything after all.".into()),
+        }
+    }
+
+    notes
+}
+
+// Roll for a mishap on the road in bad weather, returns a message if something happened
+fn travel_mishap(managers: &mut crate::ui::display::Managers) -> Option<String> {
+    let weather = managers
+        .weather_manager
+        .weather_arc_rwlock
+        .as_ref()
+        .and_then(|game_weather| game_weather.read().ok().map(|w| w.weather_type.clone()))?;
+    let day = managers
+        .time_manager
+        .now()
+        .map(|time| time.day)
+        .unwrap_or(1);
+
+    let player = managers.world_manager.player.as_mut()?;
+
+    // The Blessing of Safe Travel halves the risk
+    let mut risk = weather.travel_risk();
+    if player.is_blessed(&BlessingType::SafeTravel, day) {
+        risk /= 2;
+    }
+
+    if risk == 0 || managers.rng_manager.roll_dice(100) > risk {
+        return None;
+    }
+
+    let damage = managers.rng_manager.roll_dice(MISHAP_DAMAGE);
+    let ailment = weather.ailment();
+
+    let cause = match ailment {
+        Ailment::Chill => "The cold and wet get into your bones on the road.",
+        _ => "You take a bad fall on the road.",
+    };
+
+    player.condition.afflict(ailment);
+
+    if player.condition.damage(damage) {
+        let respawn_message = respawn(managers);
+        Some(format!("{}\n{}", cause, respawn_message))
+    } else {
+        Some(format!("{} You lose {} health.", cause, damage))
+    }
+}
+
+// Wake at the return point after collapsing, or where you fell if there isn't one
+fn respawn(managers: &mut crate::ui::display::Managers) -> String {
+    let message = match (
+        managers.world_manager.player.as_mut(),
+        managers.world_manager.world.as_ref(),
+    ) {
+        (Some(player), Some(world)) => {
+            player.condition.heal(player.condition.max_health / 2);
+
+            match player
+                .respawn_building_id
+                .and_then(|building_id| world.buildings.get(&building_id))
+            {
+                Some(building) => {
+                    player.current_town_id = building.town_id;
+                    player.current_building_id = Some(building.id);
+                    player.visited_towns.insert(building.town_id);
+                    player.visited_buildings.insert(building.id);
+                    player.current_room_id = None;
+
+                    format!(
+                        "You collapse, and wake in the care of the {}.",
+                        building.name
+                    )
+                }
+                None => "You collapse, and wake hours later by the side of the road.".into(),
+            }
+        }
+        _ => return "You collapse.".into(),
+    };
+
+    managers.time_manager.advance(COLLAPSE_TICKS);
+
+    message
+}
+
+// Pay the temple to heal your wounds
+fn heal_wounds(managers: &mut crate::ui::display::Managers) -> String {
+    let player = match managers.world_manager.player.as_mut() {
+        Some(player) => player,
+        None => return "There's nothing to heal.".into(),
+    };
+
+    let missing = player.condition.max_health - player.condition.health;
+
+    if missing == 0 {
+        return "You're in good health already.".into();
+    }
+
+    let price = crate::world::temple::heal_price(missing, player.current_town_id);
+
+    if player.gold < price {
+        return format!("Healing costs {} gold. You can't afford it.", price);
+    }
+
+    player.gold -= price;
+    managers.records_manager.spend(price);
+    player.condition.heal(missing);
+
+    format!(
+        "You pay {} gold and the priest tends to your wounds.",
+        price
+    )
+}
+
+// Pay the temple to cure your ailments
+fn cure_ailments(managers: &mut crate::ui::display::Managers) -> String {
+    let player = match managers.world_manager.player.as_mut() {
+        Some(player) => player,
+        None => return "There's nothing to cure.".into(),
+    };
+
+    if player.condition.ailments.is_empty() {
+        return "You have no ailments to cure.".into();
+    }
+
+    let price =
+        crate::world::temple::cure_price(player.condition.ailments.len(), player.current_town_id);
+
+    if player.gold < price {
+        return format!("A cure costs {} gold. You can't afford it.", price);
+    }
+
+    player.gold -= price;
+    managers.records_manager.spend(price);
+    player.condition.ailments.clear();
+
+    format!("You pay {} gold and the priest cures your ailments.", price)
+}
+
+// Pay the temple for the blessing at index
+fn receive_blessing(managers: &mut crate::ui::display::Managers, index: usize) -> String {
+    let (blessing_type, price, days) = match crate::world::temple::BLESSINGS.get(index) {
+        Some(blessing) => blessing.clone(),
+        None => return "The priest doesn't know that blessing.".into(),
+    };
+
+    let day = managers
+        .time_manager
+        .now()
+        .map(|time| time.day)
+        .unwrap_or(1);
+
+    let player = match managers.world_manager.player.as_mut() {
+        Some(player) => player,
+        None => return "The priest doesn't know that blessing.".into(),
+    };
+
+    if player.gold < price {
+        return format!(
+            "The {} costs {} gold. You can't afford it.",
+            blessing_type.name(),
+            price
+        );
+    }
+
+    player.gold -= price;
+    managers.records_manager.spend(price);
+
+    // A new blessing replaces one of the same type, and expired blessings are forgotten
+    player
+        .blessings
+        .retain(|blessing| blessing.blessing_type != blessing_type && blessing.expires_day > day);
+    player.blessings.push(crate::entities::condition::Blessing {
+        blessing_type: blessing_type.clone(),
+        expires_day: day + days,
+    });
+
+    format!(
+        "You receive the {}. It lasts until day {}.",
+        blessing_type.name(),
+        day + days
+    )
+}
+
+// Donate to the temple, raising your standing in the town
+fn donate(managers: &mut crate::ui::display::Managers) -> String {
+    let priest_id = current_proprietor_id(managers);
+
+    let player = match managers.world_manager.player.as_mut() {
+        Some(player) => player,
+        None => return "There's nothing to donate.".into(),
+    };
+
+    if player.gold < crate::world::temple::DONATION {
+        return "You don't have enough gold to donate.".into();
+    }
+
+    player.gold -= crate::world::temple::DONATION;
+    managers
+        .records_manager
+        .spend(crate::world::temple::DONATION);
+    player.change_reputation(player.current_town_id, 1);
+
+    if let Some(npc_id) = priest_id {
+        player.change_disposition(npc_id, 1);
+    }
+
+    format!(
+        "You donate {} gold. The townsfolk will hear of your generosity.",
+        crate::world::temple::DONATION
+    )
+}
+
+// Set the current temple as the place to wake after collapsing
+fn set_return_point(managers: &mut crate::ui::display::Managers) -> String {
+    let player = match managers.world_manager.player.as_mut() {
+        Some(player) => player,
+        None => return "You can't return here.".into(),
+    };
+
+    player.respawn_building_id = player.current_building_id;
+
+    "The priest writes your name in the temple book. Should you fall, you'll be brought here."
+        .into()
+}
+
+// Travel to another town by name, a coach gets there in half the time, returns a note if the
+// journey was enough to level up
+fn travel_to_town(
+    managers: &mut crate::ui::display::Managers,
+    destination: &str,
+    by_coach: bool,
+) -> Option<String> {
+    if let Some(player) = managers.world_manager.player.as_mut() {
+        // Save current town
+        let current_town = if let Some(world) = managers.world_manager.world.as_ref() {
+            if let Some(town) = world.towns.get(&player.current_town_id) {
+                town.name.clone()
+            } else {
+                log::error!(
+                    "Failed to find town name for town ID: {}",
+                    player.current_town_id
+                );
+                panic!(
+                    "Failed to find town name for town ID: {}",
+                    player.current_town_id
+                )
+            }
+        } else {
+            log::error!(
+                "Failed to find town name for town ID: {}",
+                player.current_town_id
+            );
+            panic!(
+                "Failed to find town name for town ID: {}",
+                player.current_town_id
+            )
+        };
+
+        // Change town to new town
+        let towns = if let Some(world) = managers.world_manager.world.as_ref() {
+            &world.towns
+        } else {
+            log::error!("Failed to find world data while attempting to travel.");
+            panic!("Failed to find world data while attempting to travel.")
+        };
+
+        match find_id_by_name(towns, destination) {
+            Some(id) => {
+                player.current_town_id = id;
+                player.visited_towns.insert(id);
+            }
+            None => {
+                log::error!("Failed to find town ID for town name: {}", destination);
+                panic!("Failed to find town ID for town name: {}", destination);
+            }
+        };
+
+        // Move player to outside
+        player.current_building_id = None;
+
+        // Advance time by the travel time
+        let travel_time = managers
+            .world_manager
+            .get_travel_time(&current_town, &destination.to_string());
+        managers.records_manager.travel(
+            managers
+                .world_manager
+                .get_travel_distance(&current_town, &destination.to_string()),
+        );
+        let player = managers.world_manager.player.as_mut()?;
+
+        // Every journey is a lesson, however it's made
+        let note = player.gain_experience(travel_time / TRAVEL_TICKS_PER_EXPERIENCE);
+
+        // Pathfinders know the shortcuts, but walking is tiring, less so for the hardy
+        let travel_time = if by_coach {
+            travel_time / 2
+        } else {
+            let travel_time =
+                travel_time * (100 - player.progression.pathfinding * PATHFINDING_PERCENT) / 100;
+
+            player.condition.exert(
+                travel_time / (WALKING_TICKS_PER_FATIGUE * player.attributes.stamina.max(1)),
+            );
+
+            travel_time
+        };
+
+        // Hungry or tired travellers are slower
+        let travel_time =
+            travel_time * (100 + player.condition.penalty() * NEED_TRAVEL_PERCENT) / 100;
+
+        managers.time_manager.advance(travel_time);
+
+        record_message(
+            managers,
+            &format!(
+                "Arrived in {}{}.",
+                destination,
+                if by_coach { " by coach" } else { "" }
+            ),
+        );
+
+        note
+    } else {
+        None
+    }
+}
+
+// Take an item from the open container into the inventory, returns a message and what was taken
+fn take_item(
+    managers: &mut crate::ui::display::Managers,
+    index: usize,
+) -> (String, Vec<crate::entities::inventory::InventorySlot>) {
+    let container_id = match managers.state_manager.selected_container_id {
+        Some(container_id) => container_id,
+        None => return ("There's nothing to take.".into(), Vec::new()),
+    };
+
+    let (player, contents) = match (
+        managers.world_manager.player.as_mut(),
+        managers
+            .world_manager
+            .container_contents
+            .get_mut(&container_id),
+    ) {
+        (Some(player), Some(contents)) => (player, contents),
+        _ => return ("There's nothing to take.".into(), Vec::new()),
+    };
+
+    let slot = match contents.get(index) {
+        Some(slot) => slot.clone(),
+        None => return ("There's nothing to take.".into(), Vec::new()),
+    };
+
+    match player.inventory.add(slot.item.clone(), slot.quantity) {
+        Ok(()) => {
+            contents.remove(index);
+            (format!("You take the {}.", slot.label()), vec![slot])
+        }
+        Err(e) => (e.to_string(), Vec::new()),
+    }
+}
+
+// Take everything that fits from the open container into the inventory, returns a message and
+// what was taken
+fn take_all_items(
+    managers: &mut crate::ui::display::Managers,
+) -> (String, Vec<crate::entities::inventory::InventorySlot>) {
+    let container_id = match managers.state_manager.selected_container_id {
+        Some(container_id) => container_id,
+        None => return ("There's nothing to take.".into(), Vec::new()),
+    };
+
+    let (player, contents) = match (
+        managers.world_manager.player.as_mut(),
+        managers
+            .world_manager
+            .container_contents
+            .get_mut(&container_id),
+    ) {
+        (Some(player), Some(contents)) => (player, contents),
+        _ => return ("There's nothing to take.".into(), Vec::new()),
+    };
+
+    let mut taken: Vec<crate::entities::inventory::InventorySlot> = Vec::new();
+    let mut left_behind: Vec<String> = Vec::new();
+
+    contents.retain(
+        |slot| match player.inventory.add(slot.item.clone(), slot.quantity) {
+            Ok(()) => {
+                taken.push(slot.clone());
+                false
+            }
+            Err(_) => {
+                left_behind.push(slot.label());
+                true
+            }
+        },
+    );
+
+    let mut message = if taken.is_empty() {
+        "You take nothing.".to_string()
+    } else {
+        format!(
+            "You take: {}.",
+            taken
+                .iter()
+                .map(|slot| slot.label())
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    };
+
+    if !left_behind.is_empty() {
+        message.push_str(&format!("\nYou can't carry: {}.", left_behind.join(", ")));
+    }
+
+    (message, taken)
+}
+
+// Use the selected inventory item, returns a message describing what happened
+fn use_item(managers: &mut crate::ui::display::Managers) -> String {
+    let (player, index) = match (
+        managers.world_manager.player.as_mut(),
+        managers.state_manager.selected_item_index,
+    ) {
+        (Some(player), Some(index)) => (player, index),
+        _ => return "There's nothing to use.".into(),
+    };
+
+    let item = match player.inventory.slots.get(index) {
+        Some(slot) => slot.item.clone(),
+        None => return "There's nothing to use.".into(),
+    };
+
+    let message = match item.category {
+        crate::entities::item::ItemCategory::Food => {
+            player.condition.eat(FOOD_NOURISHMENT);
+            format!("You eat the {}.", item.name)
+        }
+        crate::entities::item::ItemCategory::Drink => {
+            player.condition.refresh(DRINK_REFRESHMENT);
+            format!("You drink the {}.", item.name)
+        }
+        crate::entities::item::ItemCategory::Medicine => {
+            // Bandages bind injuries, potions break fevers
+            let (heal, ailment) = match item.id {
+                crate::entities::item::ITEM_BANDAGE => (BANDAGE_HEAL, Ailment::Injury),
+                crate::entities::item::ITEM_HEALING_POTION => (POTION_HEAL, Ailment::Fever),
+                _ => (0, Ailment::Injury),
+            };
+
+            player.condition.heal(heal);
+
+            if player.condition.cure(&ailment) {
+                format!("You use the {}. Your {:?} is cured.", item.name, ailment)
+            } else {
+                format!("You use the {}.", item.name)
+            }
+        }
+        _ => return format!("You can't use the {} right now.", item.name),
+    };
+
+    remove_selected_item(managers, index);
+
+    message
+}
+
+// Spend an attribute point, returns a message describing what happened
+fn raise_attribute(managers: &mut crate::ui::display::Managers, index: usize) -> String {
+    let (player, attribute) = match (
+        managers.world_manager.player.as_mut(),
+        crate::entities::attributes::Attribute::ALL.get(index),
+    ) {
+        (Some(player), Some(attribute)) => (player, attribute),
+        _ => return "There's nothing to raise.".into(),
+    };
+
+    if player.progression.attribute_points == 0 {
+        return "You have no attribute points to spend.".into();
+    }
+
+    if !player.attributes.raise(attribute) {
+        return format!("Your {} can't go any higher.", attribute.name());
+    }
+
+    player.progression.attribute_points -= 1;
+
+    // Stronger characters carry more
+    player.inventory.max_weight = player
+        .attributes
+        .carry_weight(crate::entities::inventory::MAX_WEIGHT);
+
+    format!(
+        "Your {} rises to {}.",
+        attribute.name(),
+        player.attributes.score(attribute)
+    )
+}
+
+// Spend a skill point, returns a message describing what happened
+fn learn_skill(managers: &mut crate::ui::display::Managers, index: usize) -> String {
+    let (player, skill) = match (
+        managers.world_manager.player.as_mut(),
+        crate::entities::progression::Skill::ALL.get(index),
+    ) {
+        (Some(player), Some(skill)) => (player, skill),
+        _ => return "There's nothing to learn.".into(),
+    };
+
+    match player.progression.learn(skill) {
+        Ok(rank) => format!("You learn {} rank {}.", skill.name(), rank),
+        Err(message) => message,
+    }
+}
+
+// Drop one of the selected inventory item, returns a message describing what happened
+fn drop_item(managers: &mut crate::ui::display::Managers) -> String {
+    let (player, index) = match (
+        managers.world_manager.player.as_mut(),
+        managers.state_manager.selected_item_index,
+    ) {
+        (Some(player), Some(index)) => (player, index),
+        _ => return "There's nothing to drop.".into(),
+    };
+
+    let item_name = match player.inventory.slots.get(index) {
+        Some(slot) => slot.item.name.clone(),
+        None => return "There's nothing to drop.".into(),
+    };
+
+    remove_selected_item(managers, index);
+
+    format!("You drop the {}.", item_name)
+}
+
+// Remove one of the item at index, heads back to the Inventory if the slot is now empty
+fn remove_selected_item(managers: &mut crate::ui::display::Managers, index: usize) {
+    if let Some(player) = managers.world_manager.player.as_mut() {
+        let slots_before = player.inventory.slots.len();
+
+        player.inventory.remove(index, 1);
+
+        if player.inventory.slots.len() < slots_before {
+            managers.state_manager.selected_item_index = None;
+            managers.state_manager.current_state = super::states::StateType::Inventory;
+        }
+    }
 }
 
 // Start the game
@@ -400,33 +2785,85 @@ fn start_game(
     managers.world_manager.clear();
 
     managers.world_manager.load_world()?;
+    managers.dialogue_manager.load_dialogue()?;
+    managers.quest_manager.load_quests()?;
+
+    managers.rng_manager.start_new();
+    managers
+        .records_manager
+        .start(None, managers.rng_manager.seed().unwrap_or_default());
 
+    // Pick the starting town, town IDs are sorted so the same seed always gives the same town
     let town_id = if let Some(world) = managers.world_manager.world.as_ref() {
-        if let Some((_key, town)) = world.towns.iter().next() {
-            town.id
-        } else {
+        let mut town_ids: Vec<u32> = world.towns.keys().copied().collect();
+        town_ids.sort();
+
+        if town_ids.is_empty() {
             log::error!("Failed to load world.");
             panic!("Failed to load world.");
         }
+
+        town_ids[managers.rng_manager.pick_index(town_ids.len())]
     } else {
         log::error!("Failed to load world.");
         panic!("Failed to load world.");
     };
 
-    managers.world_manager.player = Some(crate::entities::player::Player::new(
+    let mut player = crate::entities::player::Player::new(
         666,
         ui_components.popup.input.clone(),
         town_id,
         None,
         None,
-    ));
+    );
+
+    // Starting supplies
+    for (item_id, quantity) in [
+        (crate::entities::item::ITEM_BREAD, 2),
+        (crate::entities::item::ITEM_WATER, 2),
+        (crate::entities::item::ITEM_LOCKPICK, 2),
+    ] {
+        if let Some(item) = crate::entities::item::Item::from_id(item_id) {
+            player.inventory.add(item, quantity)?;
+        }
+    }
+
+    player.gold = STARTING_GOLD;
+    player.visited_towns.insert(town_id);
+    player.attributes = crate::entities::attributes::Attributes::roll(&managers.rng_manager);
+    player.inventory.max_weight = player
+        .attributes
+        .carry_weight(crate::entities::inventory::MAX_WEIGHT);
+
+    managers.world_manager.player = Some(player);
 
     managers
         .time_manager
         .start(crate::world::time::GameTime::new());
-    managers
-        .weather_manager
-        .start(crate::world::weather::GameWeather::new());
+    match managers.rng_manager.seed() {
+        Some(seed) => {
+            let weather_rng = Arc::new(Mutex::new(crate::core::rng::GameRng::weather(seed)));
+            let mut game_weather = crate::world::weather::GameWeather::new();
+            game_weather.forecast = crate::world::weather::WeatherType::roll(&weather_rng);
+
+            managers.weather_manager.start(game_weather, weather_rng)
+        }
+        None => return Err("Failed to start GameRng.".into()),
+    }
+
+    let town_name = managers
+        .world_manager
+        .world
+        .as_ref()
+        .and_then(|world| world.towns.get(&town_id))
+        .map(|town| town.name.clone())
+        .unwrap_or_default();
+
+    managers.message_manager.start();
+    record_message(
+        managers,
+        &format!("{} sets out from {}.", ui_components.popup.input, town_name),
+    );
 
     Ok(())
 }
@@ -438,6 +2875,8 @@ fn load_game(
     managers.world_manager.clear();
 
     managers.world_manager.load_world()?;
+    managers.dialogue_manager.load_dialogue()?;
+    managers.quest_manager.load_quests()?;
 
     let save_data = managers.save_manager.load()?;
 
@@ -447,18 +2886,58 @@ fn load_game(
         log::error!("Failed to load Player: No Player found.");
     }
 
+    if let Some(container_contents) = save_data.containers {
+        managers.world_manager.container_contents = container_contents;
+    }
+
+    if let Some(unlocked_containers) = save_data.unlocked_containers {
+        managers.world_manager.unlocked_containers = unlocked_containers;
+    }
+
+    if let Some(shop_stock) = save_data.shop_stock {
+        managers.world_manager.shop_stock = shop_stock;
+    }
+
+    if let Some(quest_boards) = save_data.quest_boards {
+        managers.world_manager.quest_boards = quest_boards;
+    }
+
     if let Some(initial_game_time) = save_data.time {
         managers.time_manager.start(initial_game_time);
     } else {
         log::error!("Failed to load GameTime: No GameTime found.");
     }
 
+    if let Some(initial_game_rng) = save_data.rng {
+        managers.rng_manager.start(initial_game_rng);
+    } else {
+        log::error!("Failed to load GameRng: No GameRng found.");
+        managers.rng_manager.start_new();
+    }
+
+    managers.records_manager.start(
+        save_data.records,
+        managers.rng_manager.seed().unwrap_or_default(),
+    );
+
     if let Some(initial_game_weather) = save_data.weather {
-        managers.weather_manager.start(initial_game_weather);
+        // Saves from before weather had its own stream start it fresh from the seed
+        let weather_rng = match (save_data.weather_rng, managers.rng_manager.seed()) {
+            (Some(weather_rng), _) => weather_rng,
+            (None, Some(seed)) => crate::core::rng::GameRng::weather(seed),
+            (None, None) => return Err("Failed to start GameRng.".into()),
+        };
+
+        managers
+            .weather_manager
+            .start(initial_game_weather, Arc::new(Mutex::new(weather_rng)));
     } else {
         log::error!("Failed to load GameWeather: No GameWeather found.");
     }
 
+    managers.message_manager.start();
+    record_message(managers, "Game loaded.");
+
     Ok(())
 }
 
@@ -479,8 +2958,81 @@ impl HasName for crate::world::manager::Building {
     }
 }
 
-fn find_id_by_name<'a, T: HasName>(items: &'a HashMap<u32, T>, name: &str) -> Option<u32> {
+fn find_id_by_name<T: HasName>(items: &HashMap<u32, T>, name: &str) -> Option<u32> {
     items
         .iter()
         .find_map(|(id, item)| if item.name() == name { Some(*id) } else { None })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Managers for a game in the test world, with the player standing outside in Ashford
+    fn managers() -> crate::ui::display::Managers {
+        let mut world_manager = crate::world::manager::tests::world_manager();
+        world_manager.player = Some(crate::entities::player::Player::new(
+            1,
+            "Bob".into(),
+            1,
+            None,
+            None,
+        ));
+
+        crate::ui::display::Managers {
+            state_manager: crate::core::states::StateManager::new(),
+            world_manager,
+            time_manager: crate::world::time::TimeManager::new(),
+            weather_manager: crate::world::weather::WeatherManager::new(),
+            save_manager: crate::core::save::SaveGameManager::new(),
+            rng_manager: crate::core::rng::RngManager::new(Some(7)),
+            dialogue_manager: crate::world::dialogue::DialogueManager::new(),
+            quest_manager: crate::world::quest::QuestManager::new(),
+            records_manager: crate::core::records::RecordsManager::new(),
+            config_manager: crate::core::config::ConfigManager::new(),
+            message_manager: crate::core::messages::MessageManager::new(),
+        }
+    }
+
+    #[test]
+    fn routes_lead_over_the_roads_and_into_the_building() {
+        let mut managers = managers();
+        managers.state_manager.selected_npc_id = Some(8);
+
+        // Towns the player hasn't been to stay hidden
+        assert_eq!(
+            plot_route(&managers),
+            "Travel ??? -> Brook (12 miles)\nGo to Brook Market\nGo to room 210"
+        );
+
+        if let Some(player) = managers.world_manager.player.as_mut() {
+            player.visited_towns.insert(1);
+        }
+        assert_eq!(
+            plot_route(&managers),
+            "Travel Ashford -> Brook (12 miles)\nGo to Brook Market\nGo to room 210"
+        );
+    }
+
+    #[test]
+    fn routes_end_where_the_npc_is() {
+        let mut managers = managers();
+        managers.state_manager.selected_npc_id = Some(7);
+
+        assert_eq!(plot_route(&managers), "Go to Temple of Ash\nGo to room 130");
+
+        if let Some(player) = managers.world_manager.player.as_mut() {
+            player.current_building_id = Some(13);
+            player.current_room_id = Some(130);
+        }
+        assert_eq!(plot_route(&managers), "Faye Holm is right here.");
+    }
+
+    #[test]
+    fn routes_need_a_road() {
+        let mut managers = managers();
+        managers.state_manager.selected_npc_id = Some(15);
+
+        assert_eq!(plot_route(&managers), "There's no road to Cove.");
+    }
+}
@@ -0,0 +1,307 @@
+use crossterm::event::KeyCode;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+// Struct for a keymap, the keys bound to each action
+pub struct Keymap {
+    bindings: HashMap<Action, Vec<KeyCode>>,
+}
+
+// Functions for keymaps
+impl Keymap {
+    // Build a keymap from a preset, with any keys from the config file taking over their actions
+    pub fn new(config: &KeymapConfig) -> Self {
+        let mut bindings: HashMap<Action, Vec<KeyCode>> = HashMap::new();
+
+        for action in Action::iter() {
+            let keys = match config.bindings.get(&action) {
+                Some(keys) => keys.iter().map(|key| key.as_str()).collect(),
+                None => config.preset.keys(&action),
+            };
+
+            let key_codes: Vec<KeyCode> = keys
+                .iter()
+                .filter_map(|key| {
+                    let key_code = parse_key(key);
+
+                    if key_code.is_none() {
+                        log::error!("Failed to parse key \"{}\" for {:?}", key, action);
+                    }
+
+                    key_code
+                })
+                .collect();
+
+            bindings.insert(action, key_codes);
+        }
+
+        Self { bindings }
+    }
+
+    // Get the action bound to a key, if there is one
+    pub fn action(&self, key_code: &KeyCode) -> Option<Action> {
+        Action::iter().find(|action| {
+            self.bindings
+                .get(action)
+                .is_some_and(|keys| keys.contains(key_code))
+        })
+    }
+
+    // Keys bound to an action, characters are left out on screens where they're typed instead
+    pub fn keys(&self, action: &Action, typing: bool) -> Vec<&KeyCode> {
+        match self.bindings.get(action) {
+            Some(keys) => keys
+                .iter()
+                .filter(|key_code| !typing || !matches!(key_code, KeyCode::Char(_)))
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    // Name of the first key bound to an action, used for short hints
+    pub fn key(&self, action: &Action, typing: bool) -> Option<String> {
+        self.keys(action, typing)
+            .first()
+            .map(|key_code| key_name(key_code))
+    }
+
+    // Describe the keys bound to an action, e.g. "k, Up"
+    pub fn describe(&self, action: &Action, typing: bool) -> String {
+        match self.keys(action, typing) {
+            keys if !keys.is_empty() => keys
+                .into_iter()
+                .map(key_name)
+                .collect::<Vec<String>>()
+                .join(", "),
+            _ => "Unbound".into(),
+        }
+    }
+}
+
+// Struct for the keymap section of the config file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct KeymapConfig {
+    #[serde(default)]
+    pub preset: Preset,
+    #[serde(default)]
+    pub bindings: HashMap<Action, Vec<String>>,
+}
+
+// Enum for actions that can be bound to keys
+#[derive(Debug, Clone, PartialEq, Eq, Hash, EnumIter, Serialize, Deserialize)]
+pub enum Action {
+    Up,
+    Down,
+    PageUp,
+    PageDown,
+    First,
+    Last,
+    Filter,
+    ViewUp,
+    ViewDown,
+    LogUp,
+    LogDown,
+    ExportLog,
+    Stats,
+    Select,
+    Back,
+    Save,
+    Quit,
+    Map,
+    Help,
+}
+
+// Functions for actions
+impl Action {
+    // Name shown to the player
+    pub fn name(&self) -> &str {
+        match self {
+            Action::Up => "Menu Up",
+            Action::Down => "Menu Down",
+            Action::PageUp => "Page Up",
+            Action::PageDown => "Page Down",
+            Action::First => "First Option",
+            Action::Last => "Last Option",
+            Action::Filter => "Filter Menu",
+            Action::ViewUp => "Scroll View Up",
+            Action::ViewDown => "Scroll View Down",
+            Action::LogUp => "Scroll Log Up",
+            Action::LogDown => "Scroll Log Down",
+            Action::ExportLog => "Export Log",
+            Action::Stats => "Toggle Stats",
+            Action::Select => "Select",
+            Action::Back => "Back",
+            Action::Save => "Save Game",
+            Action::Quit => "Quit Game",
+            Action::Map => "Open Map",
+            Action::Help => "Help",
+        }
+    }
+}
+
+// Enum for built-in keymap presets
+#[derive(Debug, Clone, Default, PartialEq, EnumIter, Serialize, Deserialize)]
+pub enum Preset {
+    #[default]
+    Arrows,
+    Vim,
+    Wasd,
+}
+
+// Functions for presets
+impl Preset {
+    // Name shown to the player
+    pub fn name(&self) -> &str {
+        match self {
+            Preset::Arrows => "Arrows",
+            Preset::Vim => "Vim",
+            Preset::Wasd => "WASD",
+        }
+    }
+
+    // Keys bound to an action, every preset keeps the arrows, Enter and Esc as a fallback
+    fn keys(&self, action: &Action) -> Vec<&'static str> {
+        match (self, action) {
+            (Preset::Arrows, Action::Up) => vec!["Up"],
+            (Preset::Arrows, Action::Down) => vec!["Down"],
+            (Preset::Arrows, Action::Select) => vec!["Enter"],
+            (Preset::Arrows, Action::Back) => vec!["Esc"],
+            (Preset::Arrows, Action::Save) => vec!["s"],
+            (Preset::Vim, Action::Up) => vec!["k", "Up"],
+            (Preset::Vim, Action::Down) => vec!["j", "Down"],
+            (Preset::Vim, Action::Select) => vec!["l", "Enter"],
+            (Preset::Vim, Action::Back) => vec!["h", "Esc"],
+            (Preset::Vim, Action::Save) => vec!["w"],
+            (Preset::Wasd, Action::Up) => vec!["w", "Up"],
+            (Preset::Wasd, Action::Down) => vec!["s", "Down"],
+            (Preset::Wasd, Action::Select) => vec!["d", "Enter"],
+            (Preset::Wasd, Action::Back) => vec!["a", "Esc"],
+            (Preset::Wasd, Action::Save) => vec!["F5"],
+            (_, Action::PageUp) => vec!["PageUp"],
+            (_, Action::PageDown) => vec!["PageDown"],
+            (_, Action::First) => vec!["Home"],
+            (_, Action::Last) => vec!["End"],
+            (_, Action::Filter) => vec!["/"],
+            (_, Action::ViewUp) => vec!["["],
+            (_, Action::ViewDown) => vec!["]"],
+            (_, Action::LogUp) => vec!["{"],
+            (_, Action::LogDown) => vec!["}"],
+            (_, Action::ExportLog) => vec!["F2"],
+            (_, Action::Stats) => vec!["Tab"],
+            (_, Action::Quit) => vec!["q"],
+            (_, Action::Map) => vec!["m"],
+            (_, Action::Help) => vec!["?", "F1"],
+        }
+    }
+}
+
+// Parse a key from the config file, either a single character or a key name like "Enter" or "F5"
+fn parse_key(key: &str) -> Option<KeyCode> {
+    let mut chars = key.chars();
+
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Some(KeyCode::Char(c));
+    }
+
+    match key {
+        "Up" => Some(KeyCode::Up),
+        "Down" => Some(KeyCode::Down),
+        "Left" => Some(KeyCode::Left),
+        "Right" => Some(KeyCode::Right),
+        "Enter" => Some(KeyCode::Enter),
+        "Esc" => Some(KeyCode::Esc),
+        "Backspace" => Some(KeyCode::Backspace),
+        "Tab" => Some(KeyCode::Tab),
+        "Space" => Some(KeyCode::Char(' ')),
+        "PageUp" => Some(KeyCode::PageUp),
+        "PageDown" => Some(KeyCode::PageDown),
+        "Home" => Some(KeyCode::Home),
+        "End" => Some(KeyCode::End),
+        _ => key
+            .strip_prefix('F')
+            .and_then(|number| number.parse::<u8>().ok())
+            .filter(|number| (1..=12).contains(number))
+            .map(KeyCode::F),
+    }
+}
+
+// Name of a key shown to the player, the same as in the config file
+fn key_name(key_code: &KeyCode) -> String {
+    match key_code {
+        KeyCode::Char(' ') => "Space".into(),
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::F(number) => format!("F{}", number),
+        KeyCode::PageUp => "PageUp".into(),
+        KeyCode::PageDown => "PageDown".into(),
+        other => format!("{:?}", other),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys_parse_from_characters_and_names() {
+        assert_eq!(parse_key("k"), Some(KeyCode::Char('k')));
+        assert_eq!(parse_key("Space"), Some(KeyCode::Char(' ')));
+        assert_eq!(parse_key("Enter"), Some(KeyCode::Enter));
+        assert_eq!(parse_key("F12"), Some(KeyCode::F(12)));
+
+        for key in ["", "F0", "F13", "Ctrl", "up"] {
+            assert_eq!(parse_key(key), None, "{:?}", key);
+        }
+    }
+
+    #[test]
+    fn no_preset_binds_a_key_twice() {
+        for preset in Preset::iter() {
+            let mut bound: HashMap<String, Action> = HashMap::new();
+
+            for action in Action::iter() {
+                for key in preset.keys(&action) {
+                    // Every preset key parses and is shown the way it's written
+                    assert_eq!(parse_key(key).as_ref().map(key_name).as_deref(), Some(key));
+
+                    if let Some(other) = bound.insert(key.to_string(), action.clone()) {
+                        panic!(
+                            "{} binds {} to both {:?} and {:?}",
+                            preset.name(),
+                            key,
+                            other,
+                            action
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn config_bindings_take_over_their_actions() {
+        let keymap = Keymap::new(&KeymapConfig {
+            preset: Preset::Vim,
+            bindings: HashMap::from([(Action::Save, vec!["F5".into(), "Nope".into()])]),
+        });
+
+        assert_eq!(keymap.action(&KeyCode::F(5)), Some(Action::Save));
+        assert_eq!(keymap.action(&KeyCode::Char('w')), None);
+        assert_eq!(keymap.action(&KeyCode::Char('k')), Some(Action::Up));
+        assert_eq!(keymap.describe(&Action::Save, false), "F5");
+    }
+
+    #[test]
+    fn characters_are_left_out_while_typing() {
+        let keymap = Keymap::new(&KeymapConfig {
+            preset: Preset::Vim,
+            ..Default::default()
+        });
+
+        assert_eq!(keymap.describe(&Action::Up, false), "k, Up");
+        assert_eq!(keymap.describe(&Action::Up, true), "Up");
+        assert_eq!(keymap.key(&Action::Help, true).as_deref(), Some("F1"));
+        assert_eq!(keymap.describe(&Action::Quit, true), "Unbound");
+    }
+}
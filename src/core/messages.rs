@@ -0,0 +1,97 @@
+use crate::world::weather::WeatherType;
+use std::fs;
+use std::path::PathBuf;
+
+// Const for the most messages kept, the oldest are dropped first
+const MAX_MESSAGES: usize = 500;
+
+// Struct for Message Manager
+pub struct MessageManager {
+    pub messages: Vec<Message>,
+    last_weather: Option<WeatherType>,
+}
+
+// Functions for Message Manager
+impl MessageManager {
+    // Create a new Message Manager with an empty log
+    pub fn new() -> Self {
+        Self {
+            messages: Vec::new(),
+            last_weather: None,
+        }
+    }
+
+    // Start a fresh log for a new or loaded game
+    pub fn start(&mut self) {
+        self.messages.clear();
+        self.last_weather = None;
+    }
+
+    // Add a message to the log, stamped with the in-game time if a game is running
+    pub fn record(&mut self, time: Option<crate::world::time::GameTime>, text: &str) {
+        let timestamp = match time {
+            Some(time) => time.timestamp(),
+            None => "--".into(),
+        };
+
+        // Notices can span several lines, the log keeps one line per message
+        let text = text
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .collect::<Vec<&str>>()
+            .join(" ");
+
+        self.messages.push(Message { timestamp, text });
+
+        if self.messages.len() > MAX_MESSAGES {
+            self.messages.remove(0);
+        }
+    }
+
+    // Note when the weather changes
+    pub fn update_weather(
+        &mut self,
+        time: Option<crate::world::time::GameTime>,
+        weather: Option<WeatherType>,
+    ) {
+        let weather = match weather {
+            Some(weather) => weather,
+            None => return,
+        };
+
+        if self
+            .last_weather
+            .as_ref()
+            .is_some_and(|last_weather| *last_weather != weather)
+        {
+            self.record(time, &format!("The weather turns {:?}.", weather));
+        }
+
+        self.last_weather = Some(weather);
+    }
+
+    // Export the log to a text file, returns where it was written
+    pub fn export(&self) -> Result<PathBuf, std::io::Error> {
+        let log_path = PathBuf::from("saves").join("message_log.txt");
+
+        if let Some(parent) = log_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let text: String = self
+            .messages
+            .iter()
+            .map(|message| format!("[{}] {}\n", message.timestamp, message.text))
+            .collect();
+
+        fs::write(&log_path, text)?;
+
+        Ok(log_path)
+    }
+}
+
+// Struct for a message in the log
+pub struct Message {
+    pub timestamp: String,
+    pub text: String,
+}
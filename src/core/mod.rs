@@ -1,3 +1,8 @@
+pub mod config;
 pub mod events;
+pub mod keymap;
+pub mod messages;
+pub mod records;
+pub mod rng;
 pub mod save;
 pub mod states;
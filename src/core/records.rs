@@ -0,0 +1,435 @@
+use crate::world::weather::WeatherType;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::PathBuf;
+use strum::{EnumCount, IntoEnumIterator};
+use strum_macros::EnumIter;
+
+// Struct for Records Manager
+pub struct RecordsManager {
+    pub game: Records,
+    pub lifetime: Records,
+    counted: HashMap<u64, Totals>,
+    unlocked: Vec<Achievement>,
+    last_day: Option<u32>,
+    dirty: bool,
+}
+
+// Functions for Records Manager
+impl RecordsManager {
+    // Create a new Records Manager, picking up the records from every game played before
+    pub fn new() -> Self {
+        let lifetime_file = match load_lifetime() {
+            Ok(lifetime_file) => lifetime_file,
+            Err(e) => {
+                log::error!("Failed to load records: {}", e);
+                LifetimeFile::default()
+            }
+        };
+
+        Self {
+            game: Records::default(),
+            lifetime: lifetime_file.records,
+            counted: lifetime_file.counted,
+            unlocked: Vec::new(),
+            last_day: None,
+            dirty: false,
+        }
+    }
+
+    // Start keeping records for a game, picking up where a saved game left off
+    pub fn start(&mut self, records: Option<Records>, seed: u64) {
+        self.game = records.unwrap_or_default();
+        self.unlocked.clear();
+        self.last_day = None;
+
+        // New games get an ID from their seed, saves from before games had one were already
+        // counted this far
+        // Playing the same seed again is a different game, so it takes the next free ID
+        if self.game.game_id.is_none() {
+            let mut game_id = seed;
+
+            while self.counted.contains_key(&game_id) {
+                game_id = game_id.wrapping_add(1);
+            }
+
+            self.game.game_id = Some(game_id);
+            self.counted
+                .insert(game_id, Totals::from(&self.game.statistics));
+        }
+    }
+
+    // Save the records from every game if anything has changed since they were last saved
+    pub fn flush(&mut self) {
+        if !self.dirty {
+            return;
+        }
+
+        match self.save_lifetime() {
+            Ok(()) => self.dirty = false,
+            Err(e) => log::error!("Failed to save records: {}", e),
+        }
+    }
+
+    // Save the records from every game to JSON, along with how much of each game they include
+    fn save_lifetime(&self) -> Result<(), std::io::Error> {
+        let json = serde_json::to_string_pretty(&LifetimeFile {
+            records: self.lifetime.clone(),
+            counted: self.counted.clone(),
+        })?;
+
+        let records_path = PathBuf::from("saves").join("records.json");
+
+        if let Some(parent) = records_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(&records_path, json)?;
+
+        Ok(())
+    }
+
+    // Keep track of days survived and the weather, anything that changes over time
+    pub fn update(
+        &mut self,
+        world_manager: &crate::world::manager::WorldManager,
+        time: Option<crate::world::time::GameTime>,
+        weather: Option<WeatherType>,
+    ) {
+        if world_manager.player.is_none() {
+            return;
+        }
+
+        if let Some(time) = time {
+            let days = time.day.saturating_sub(self.last_day.unwrap_or(time.day));
+            self.last_day = Some(time.day);
+
+            if days > 0 {
+                self.record(|statistics| statistics.days_survived += days);
+            }
+        }
+
+        if let Some(weather) =
+            weather.filter(|weather| !self.game.statistics.weather_seen.contains(weather))
+        {
+            self.record(|statistics| {
+                statistics.weather_seen.insert(weather.clone());
+            });
+        }
+    }
+
+    // Record gold spent on goods and services
+    pub fn spend(&mut self, gold: u32) {
+        self.record(|statistics| statistics.gold_spent += gold);
+    }
+
+    // Record a journey
+    pub fn travel(&mut self, miles: u32) {
+        self.record(|statistics| statistics.miles_travelled += miles);
+    }
+
+    // Record meeting an NPC
+    pub fn meet_npc(&mut self, npc_id: u32) {
+        self.record(|statistics| {
+            statistics.npcs_met.insert(npc_id);
+        });
+    }
+
+    // Record opening a container
+    pub fn open_container(&mut self, container_id: u32) {
+        self.record(|statistics| {
+            statistics.containers_opened.insert(container_id);
+        });
+    }
+
+    // Announcement for the next achievement earned this game that hasn't been shown yet
+    pub fn take_unlocked(&mut self) -> Option<String> {
+        if self.unlocked.is_empty() {
+            return None;
+        }
+
+        let achievement = self.unlocked.remove(0);
+
+        Some(format!(
+            "Achievement unlocked: {}!\n{}",
+            achievement.name(),
+            achievement.description()
+        ))
+    }
+
+    // Record something in this game and across all games, then look for new achievements
+    // Only what the game gains past its best so far counts across all games, so playing on from
+    // an older save doesn't count the same journey twice
+    fn record(&mut self, change: impl Fn(&mut Statistics)) {
+        change(&mut self.game.statistics);
+
+        let counted = self
+            .counted
+            .entry(self.game.game_id.unwrap_or_default())
+            .or_default();
+        self.lifetime
+            .statistics
+            .count(&self.game.statistics, counted);
+
+        self.unlocked.extend(self.game.unlock());
+        self.lifetime.unlock();
+
+        // Saved with the game, when it ends or when quitting
+        self.dirty = true;
+    }
+}
+
+// Load the records from every game played before, there aren't any on a first run
+fn load_lifetime() -> Result<LifetimeFile, Box<dyn std::error::Error>> {
+    let records_path = PathBuf::from("saves").join("records.json");
+
+    if !records_path.exists() {
+        return Ok(LifetimeFile::default());
+    }
+
+    let json_data = fs::read_to_string(records_path)?;
+
+    let lifetime_file: LifetimeFile = serde_json::from_str(&json_data)?;
+
+    Ok(lifetime_file)
+}
+
+// Struct for the records file, the records from every game and the totals counted from each game
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct LifetimeFile {
+    #[serde(flatten)]
+    records: Records,
+    #[serde(default)]
+    counted: HashMap<u64, Totals>,
+}
+
+// Struct for records, the statistics and achievements of one game or every game
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Records {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub game_id: Option<u64>,
+    #[serde(default)]
+    pub statistics: Statistics,
+    #[serde(default)]
+    pub achievements: Vec<Achievement>,
+}
+
+// Functions for records
+impl Records {
+    // Earn any achievements the statistics now qualify for, returns the ones just earned
+    fn unlock(&mut self) -> Vec<Achievement> {
+        let earned: Vec<Achievement> = Achievement::iter()
+            .filter(|achievement| !self.achievements.contains(achievement))
+            .filter(|achievement| achievement.is_earned(&self.statistics))
+            .collect();
+
+        self.achievements.extend(earned.iter().cloned());
+
+        earned
+    }
+}
+
+// Struct for statistics
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Statistics {
+    #[serde(default)]
+    pub miles_travelled: u32,
+    #[serde(default)]
+    pub gold_spent: u32,
+    #[serde(default)]
+    pub days_survived: u32,
+    #[serde(default)]
+    pub npcs_met: HashSet<u32>,
+    #[serde(default)]
+    pub containers_opened: HashSet<u32>,
+    #[serde(default)]
+    pub weather_seen: HashSet<WeatherType>,
+}
+
+// Functions for statistics
+impl Statistics {
+    // Add what a game has gained since it was last counted
+    fn count(&mut self, game: &Statistics, counted: &mut Totals) {
+        self.miles_travelled += game.miles_travelled.saturating_sub(counted.miles_travelled);
+        self.gold_spent += game.gold_spent.saturating_sub(counted.gold_spent);
+        self.days_survived += game.days_survived.saturating_sub(counted.days_survived);
+
+        self.npcs_met.extend(game.npcs_met.iter().copied());
+        self.containers_opened
+            .extend(game.containers_opened.iter().copied());
+        self.weather_seen.extend(game.weather_seen.iter().cloned());
+
+        counted.miles_travelled = counted.miles_travelled.max(game.miles_travelled);
+        counted.gold_spent = counted.gold_spent.max(game.gold_spent);
+        counted.days_survived = counted.days_survived.max(game.days_survived);
+    }
+
+    // Weather experienced, in the order the weather types are declared
+    pub fn describe_weather(&self) -> String {
+        let seen: Vec<String> = WeatherType::iter()
+            .filter(|weather| self.weather_seen.contains(weather))
+            .map(|weather| format!("{:?}", weather))
+            .collect();
+
+        if seen.is_empty() {
+            "None".into()
+        } else {
+            seen.join(", ")
+        }
+    }
+}
+
+// Struct for the running totals of a game that have been counted across all games
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct Totals {
+    miles_travelled: u32,
+    gold_spent: u32,
+    days_survived: u32,
+}
+
+impl From<&Statistics> for Totals {
+    fn from(statistics: &Statistics) -> Self {
+        Self {
+            miles_travelled: statistics.miles_travelled,
+            gold_spent: statistics.gold_spent,
+            days_survived: statistics.days_survived,
+        }
+    }
+}
+
+// Enum for achievements
+#[derive(Debug, Clone, PartialEq, EnumIter, Serialize, Deserialize)]
+pub enum Achievement {
+    Wanderer,
+    RoadWarrior,
+    BigSpender,
+    Survivor,
+    Socialite,
+    TreasureHunter,
+    Weathered,
+}
+
+// Functions for achievements
+impl Achievement {
+    // Name shown to the player
+    pub fn name(&self) -> &str {
+        match self {
+            Achievement::Wanderer => "Wanderer",
+            Achievement::RoadWarrior => "Road Warrior",
+            Achievement::BigSpender => "Big Spender",
+            Achievement::Survivor => "Survivor",
+            Achievement::Socialite => "Socialite",
+            Achievement::TreasureHunter => "Treasure Hunter",
+            Achievement::Weathered => "Weathered",
+        }
+    }
+
+    // How to earn it
+    pub fn description(&self) -> &str {
+        match self {
+            Achievement::Wanderer => "Travel 100 miles.",
+            Achievement::RoadWarrior => "Travel 1000 miles.",
+            Achievement::BigSpender => "Spend 500 gold.",
+            Achievement::Survivor => "Survive 7 days.",
+            Achievement::Socialite => "Meet 10 people.",
+            Achievement::TreasureHunter => "Open 25 containers.",
+            Achievement::Weathered => "Experience every kind of weather.",
+        }
+    }
+
+    // Whether the statistics are good enough to earn it
+    fn is_earned(&self, statistics: &Statistics) -> bool {
+        match self {
+            Achievement::Wanderer => statistics.miles_travelled >= 100,
+            Achievement::RoadWarrior => statistics.miles_travelled >= 1000,
+            Achievement::BigSpender => statistics.gold_spent >= 500,
+            Achievement::Survivor => statistics.days_survived >= 7,
+            Achievement::Socialite => statistics.npcs_met.len() >= 10,
+            Achievement::TreasureHunter => statistics.containers_opened.len() >= 25,
+            Achievement::Weathered => statistics.weather_seen.len() >= WeatherType::COUNT,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn playing_on_from_an_older_save_only_counts_new_progress() {
+        let mut lifetime = Statistics::default();
+        let mut counted = Totals::default();
+
+        // Travel 30 miles, load a save from 10 miles in, travel the same 20 again and then 5 more
+        for miles_travelled in [30, 10, 30, 35] {
+            let game = Statistics {
+                miles_travelled,
+                ..Default::default()
+            };
+            lifetime.count(&game, &mut counted);
+        }
+
+        assert_eq!(lifetime.miles_travelled, 35);
+    }
+
+    #[test]
+    fn game_ids_come_from_the_seed() {
+        let mut records_manager = RecordsManager {
+            game: Records::default(),
+            lifetime: Records::default(),
+            counted: HashMap::new(),
+            unlocked: Vec::new(),
+            last_day: None,
+            dirty: false,
+        };
+
+        records_manager.start(None, 7);
+        assert_eq!(records_manager.game.game_id, Some(7));
+
+        // A second game on the same seed doesn't share the first one's totals
+        records_manager.start(None, 7);
+        assert_eq!(records_manager.game.game_id, Some(8));
+
+        // Loading a save keeps its ID
+        records_manager.start(
+            Some(Records {
+                game_id: Some(7),
+                ..Default::default()
+            }),
+            7,
+        );
+        assert_eq!(records_manager.game.game_id, Some(7));
+    }
+
+    #[test]
+    fn records_wait_to_be_saved() {
+        let mut records_manager = RecordsManager {
+            game: Records::default(),
+            lifetime: Records::default(),
+            counted: HashMap::new(),
+            unlocked: Vec::new(),
+            last_day: None,
+            dirty: false,
+        };
+
+        records_manager.start(None, 7);
+        assert!(!records_manager.dirty);
+
+        records_manager.travel(5);
+        assert!(records_manager.dirty);
+        assert_eq!(records_manager.lifetime.statistics.miles_travelled, 5);
+    }
+
+    #[test]
+    fn sets_are_merged_across_games() {
+        let mut lifetime = Statistics::default();
+        let mut game = Statistics::default();
+
+        game.npcs_met.extend([1, 2]);
+        lifetime.count(&game, &mut Totals::default());
+        lifetime.count(&game, &mut Totals::default());
+
+        assert_eq!(lifetime.npcs_met.len(), 2);
+    }
+}
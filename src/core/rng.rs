@@ -0,0 +1,196 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha12Rng;
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+
+// Const for the stream the weather rolls from, kept apart from the player's rolls
+const WEATHER_STREAM: u64 = 1;
+
+// Struct for RNG Manager
+pub struct RngManager {
+    pub rng_arc_mutex: Option<Arc<Mutex<GameRng>>>,
+    seed_override: Option<u64>,
+}
+
+// Functions for RNG Manager
+impl RngManager {
+    // Create a new RNG Manager, seed_override comes from the --seed argument
+    pub fn new(seed_override: Option<u64>) -> Self {
+        Self {
+            rng_arc_mutex: None,
+            seed_override,
+        }
+    }
+
+    // Start a fresh RNG for a New Game, uses the seed override if there is one
+    pub fn start_new(&mut self) {
+        let seed = self
+            .seed_override
+            .unwrap_or_else(|| rand::rng().random::<u64>());
+
+        log::info!("Starting new game with seed: {}", seed);
+
+        self.start(GameRng::new(seed));
+    }
+
+    // Start the RNG from an existing state (e.g. from a save)
+    pub fn start(&mut self, initial_game_rng: GameRng) {
+        self.rng_arc_mutex = Some(Arc::new(Mutex::new(initial_game_rng)));
+    }
+
+    // Roll a dice, returns a number between 1 and dice_size
+    pub fn roll_dice(&self, dice_size: u32) -> u32 {
+        match self.rng_arc_mutex.as_ref() {
+            Some(game_rng) => roll_dice(game_rng, dice_size),
+            None => {
+                log::error!("Failed to roll dice: No GameRng found.");
+                1
+            }
+        }
+    }
+
+    // Pick a random index for a list of the given length
+    pub fn pick_index(&self, len: usize) -> usize {
+        (self.roll_dice(len as u32) - 1) as usize
+    }
+
+    // Get the seed of the current game
+    pub fn seed(&self) -> Option<u64> {
+        self.rng_arc_mutex
+            .as_ref()
+            .and_then(|game_rng| game_rng.lock().ok().map(|rng| rng.seed))
+    }
+}
+
+// Function for dice rolls, shared with threads that hold a clone of the RNG
+pub fn roll_dice(game_rng: &Mutex<GameRng>, dice_size: u32) -> u32 {
+    if dice_size == 0 {
+        return 1;
+    }
+
+    match game_rng.lock() {
+        Ok(mut rng) => rng.roll_dice(dice_size),
+        Err(_) => {
+            log::error!("Failed to lock GameRng (lock poisoned?).");
+            1
+        }
+    }
+}
+
+// Struct for Game RNG
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "RngState", into = "RngState")]
+pub struct GameRng {
+    pub seed: u64,
+    stream: u64,
+    rng: ChaCha12Rng,
+}
+
+// Functions for Game RNG
+impl GameRng {
+    // Create a new Game RNG from a seed
+    pub fn new(seed: u64) -> Self {
+        Self::with_stream(seed, 0)
+    }
+
+    // Create the weather's Game RNG from the same seed, the weather thread rolls on a timer
+    // so sharing a stream would shift the player's rolls depending on how long they took
+    pub fn weather(seed: u64) -> Self {
+        Self::with_stream(seed, WEATHER_STREAM)
+    }
+
+    // Create a Game RNG from a seed and a stream
+    fn with_stream(seed: u64, stream: u64) -> Self {
+        let mut rng = ChaCha12Rng::seed_from_u64(seed);
+        rng.set_stream(stream);
+
+        Self { seed, stream, rng }
+    }
+
+    // Roll a dice, returns a number between 1 and dice_size
+    fn roll_dice(&mut self, dice_size: u32) -> u32 {
+        self.rng.random_range(1..=dice_size)
+    }
+}
+
+// Struct for the saved state of the Game RNG (seed and position in the stream)
+#[derive(Serialize, Deserialize)]
+struct RngState {
+    seed: u64,
+    #[serde(default)]
+    stream: u64,
+    word_pos: u128,
+}
+
+impl From<RngState> for GameRng {
+    fn from(state: RngState) -> Self {
+        let mut game_rng = GameRng::with_stream(state.seed, state.stream);
+        game_rng.rng.set_word_pos(state.word_pos);
+        game_rng
+    }
+}
+
+impl From<GameRng> for RngState {
+    fn from(game_rng: GameRng) -> Self {
+        Self {
+            seed: game_rng.seed,
+            stream: game_rng.stream,
+            word_pos: game_rng.rng.get_word_pos(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_rolls_the_same_sequence() {
+        let mut first = GameRng::new(7);
+        let mut second = GameRng::new(7);
+
+        let first_rolls: Vec<u32> = (0..100).map(|_| first.roll_dice(20)).collect();
+        let second_rolls: Vec<u32> = (0..100).map(|_| second.roll_dice(20)).collect();
+
+        assert_eq!(first_rolls, second_rolls);
+    }
+
+    #[test]
+    fn weather_rolls_do_not_shift_game_rolls() {
+        let mut untouched = GameRng::new(7);
+        let mut game_rng = GameRng::new(7);
+        let mut weather_rng = GameRng::weather(7);
+
+        let expected: Vec<u32> = (0..10).map(|_| untouched.roll_dice(20)).collect();
+        let rolls: Vec<u32> = (0..10)
+            .map(|_| {
+                weather_rng.roll_dice(100);
+                game_rng.roll_dice(20)
+            })
+            .collect();
+
+        assert_eq!(rolls, expected);
+    }
+
+    #[test]
+    fn saved_state_continues_the_sequence() {
+        let mut game_rng = GameRng::weather(7);
+        game_rng.roll_dice(6);
+
+        let json = serde_json::to_string(&game_rng).unwrap();
+        let mut loaded: GameRng = serde_json::from_str(&json).unwrap();
+
+        let rolls: Vec<u32> = (0..10).map(|_| game_rng.roll_dice(6)).collect();
+        let loaded_rolls: Vec<u32> = (0..10).map(|_| loaded.roll_dice(6)).collect();
+
+        assert_eq!(rolls, loaded_rolls);
+    }
+
+    #[test]
+    fn roll_dice_stays_in_range() {
+        let game_rng = Mutex::new(GameRng::new(7));
+
+        assert_eq!(roll_dice(&game_rng, 0), 1);
+        assert!((0..100).all(|_| (1..=6).contains(&roll_dice(&game_rng, 6))));
+    }
+}
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use serde_json;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::PathBuf;
 
@@ -23,6 +24,8 @@ impl SaveGameManager {
         world_manager: &crate::world::manager::WorldManager,
         time_manager: &crate::world::time::TimeManager,
         weather_manager: &crate::world::weather::WeatherManager,
+        rng_manager: &crate::core::rng::RngManager,
+        records_manager: &crate::core::records::RecordsManager,
     ) -> Result<(), std::io::Error> {
         // Save player
         if let Some(player) = &world_manager.player {
@@ -31,6 +34,19 @@ impl SaveGameManager {
             log::error!("Failed to save Player: No Player found.")
         }
 
+        // Save container contents
+        self.save_data.containers = Some(world_manager.container_contents.clone());
+        self.save_data.unlocked_containers = Some(world_manager.unlocked_containers.clone());
+
+        // Save shop stock
+        self.save_data.shop_stock = Some(world_manager.shop_stock.clone());
+
+        // Save quest boards
+        self.save_data.quest_boards = Some(world_manager.quest_boards.clone());
+
+        // Save records
+        self.save_data.records = Some(records_manager.game.clone());
+
         // Save time
         self.save_data.time = time_manager
             .time_arc_rwlock
@@ -51,6 +67,25 @@ impl SaveGameManager {
             log::error!("Failed to save GameWeather: No GameWeather found.")
         }
 
+        // Save RNG
+        self.save_data.rng = rng_manager
+            .rng_arc_mutex
+            .as_ref()
+            .and_then(|game_rng| game_rng.lock().ok().map(|r| r.clone()));
+
+        if self.save_data.rng.is_none() {
+            log::error!("Failed to save GameRng: No GameRng found.")
+        }
+
+        self.save_data.weather_rng = weather_manager
+            .rng_arc_mutex
+            .as_ref()
+            .and_then(|weather_rng| weather_rng.lock().ok().map(|r| r.clone()));
+
+        if self.save_data.weather_rng.is_none() {
+            log::error!("Failed to save weather GameRng: No GameRng found.")
+        }
+
         // Serialize JSON
         let json = serde_json::to_string_pretty(&self.save_data)?;
 
@@ -84,8 +119,15 @@ impl SaveGameManager {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SaveData {
     pub player: Option<crate::entities::player::Player>,
+    pub containers: Option<HashMap<u32, Vec<crate::entities::inventory::InventorySlot>>>,
+    pub unlocked_containers: Option<HashSet<u32>>,
+    pub shop_stock: Option<HashMap<u32, crate::world::shop::ShopStock>>,
+    pub quest_boards: Option<HashMap<u32, crate::world::quest::QuestBoard>>,
+    pub records: Option<crate::core::records::Records>,
     pub time: Option<crate::world::time::GameTime>,
     pub weather: Option<crate::world::weather::GameWeather>,
+    pub rng: Option<crate::core::rng::GameRng>,
+    pub weather_rng: Option<crate::core::rng::GameRng>,
 }
 
 // Functions for Save Data
@@ -94,8 +136,15 @@ impl SaveData {
     fn new() -> Self {
         Self {
             player: None,
+            containers: None,
+            unlocked_containers: None,
+            shop_stock: None,
+            quest_boards: None,
+            records: None,
             time: None,
             weather: None,
+            rng: None,
+            weather_rng: None,
         }
     }
 }
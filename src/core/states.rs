@@ -13,14 +13,48 @@ pub enum StateType {
     Time,
     Weather,
     TravelTown,
+    TravelConfirm,
     TravelBuilding,
     Building,
     Room,
+    Inventory,
+    InventoryItem,
+    Notice,
+    Container,
+    Lockpick,
+    Shop,
+    ShopBuy,
+    ShopSell,
+    Tavern,
+    TavernCoach,
+    TavernBoard,
+    Temple,
+    TempleBlessing,
+    Npc,
+    Dialogue,
+    Directory,
+    DirectoryNpc,
+    Journal,
+    JournalQuest,
+    Character,
+    Map,
+    Records,
+    Keybindings,
+    Themes,
 }
 
 // Struct for State Manager
 pub struct StateManager {
     pub current_state: StateType,
+    pub return_state: StateType,
+    pub back_state: StateType,
+    pub selected_item_index: Option<usize>,
+    pub selected_container_id: Option<u32>,
+    pub selected_npc_id: Option<u32>,
+    pub selected_quest_index: Option<usize>,
+    pub search_query: String,
+    pub plotted_route: Option<String>,
+    pub selected_town: Option<String>,
 }
 
 // Functions for State Manager
@@ -29,6 +63,15 @@ impl StateManager {
     pub fn new() -> Self {
         Self {
             current_state: StateType::MainMenu,
+            return_state: StateType::MainMenu,
+            back_state: StateType::MainMenu,
+            selected_item_index: None,
+            selected_container_id: None,
+            selected_npc_id: None,
+            selected_quest_index: None,
+            search_query: String::new(),
+            plotted_route: None,
+            selected_town: None,
         }
     }
 }
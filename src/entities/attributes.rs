@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+
+// Consts for attribute scores, an average score is 5
+const BASE_ATTRIBUTE: u32 = 2;
+const ATTRIBUTE_DICE: u32 = 6;
+const AVERAGE_ATTRIBUTE: u32 = 5;
+
+// Const for the extra weight carried per point of strength above average
+const WEIGHT_PER_STRENGTH: u32 = 4;
+
+// Struct for the player's attributes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Attributes {
+    pub strength: u32,
+    pub charisma: u32,
+    pub stamina: u32,
+}
+
+// Functions for attributes
+impl Attributes {
+    // Create average attributes
+    pub fn new() -> Self {
+        Self {
+            strength: AVERAGE_ATTRIBUTE,
+            charisma: AVERAGE_ATTRIBUTE,
+            stamina: AVERAGE_ATTRIBUTE,
+        }
+    }
+
+    // Roll attributes for a new character, each from 3 to 8
+    pub fn roll(rng_manager: &crate::core::rng::RngManager) -> Self {
+        Self {
+            strength: BASE_ATTRIBUTE + rng_manager.roll_dice(ATTRIBUTE_DICE),
+            charisma: BASE_ATTRIBUTE + rng_manager.roll_dice(ATTRIBUTE_DICE),
+            stamina: BASE_ATTRIBUTE + rng_manager.roll_dice(ATTRIBUTE_DICE),
+        }
+    }
+
+    // How much the player can carry, stronger characters carry more
+    pub fn carry_weight(&self, base_weight: u32) -> u32 {
+        (base_weight + self.strength * WEIGHT_PER_STRENGTH)
+            .saturating_sub(AVERAGE_ATTRIBUTE * WEIGHT_PER_STRENGTH)
+    }
+
+    // Bonus to standing when haggling, charming characters get better prices
+    pub fn charm(&self) -> i32 {
+        self.charisma as i32 - AVERAGE_ATTRIBUTE as i32
+    }
+
+    // Current score in an attribute
+    pub fn score(&self, attribute: &Attribute) -> u32 {
+        match attribute {
+            Attribute::Strength => self.strength,
+            Attribute::Charisma => self.charisma,
+            Attribute::Stamina => self.stamina,
+        }
+    }
+
+    // Raise an attribute by a point, returns false if it's already as high as it goes
+    pub fn raise(&mut self, attribute: &Attribute) -> bool {
+        let score = match attribute {
+            Attribute::Strength => &mut self.strength,
+            Attribute::Charisma => &mut self.charisma,
+            Attribute::Stamina => &mut self.stamina,
+        };
+
+        if *score >= super::progression::MAX_ATTRIBUTE {
+            return false;
+        }
+
+        *score += 1;
+        true
+    }
+
+    // Description used on the Stats screen, e.g. "STR 6, CHA 4, STA 7"
+    pub fn describe(&self) -> String {
+        format!(
+            "STR {}, CHA {}, STA {}",
+            self.strength, self.charisma, self.stamina
+        )
+    }
+}
+
+impl Default for Attributes {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Enum for attribute kinds
+#[derive(Debug, Clone, PartialEq)]
+pub enum Attribute {
+    Strength,
+    Charisma,
+    Stamina,
+}
+
+// Functions for attribute kinds
+impl Attribute {
+    // Every attribute, in the order shown on the Character screen
+    pub const ALL: [Attribute; 3] = [Attribute::Strength, Attribute::Charisma, Attribute::Stamina];
+
+    // Name shown to the player
+    pub fn name(&self) -> &str {
+        match self {
+            Attribute::Strength => "Strength",
+            Attribute::Charisma => "Charisma",
+            Attribute::Stamina => "Stamina",
+        }
+    }
+
+    // What the attribute does
+    pub fn effect(&self) -> &str {
+        match self {
+            Attribute::Strength => "how much you can carry",
+            Attribute::Charisma => "the prices you're offered",
+            Attribute::Stamina => "how quickly you tire",
+        }
+    }
+}
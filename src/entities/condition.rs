@@ -0,0 +1,295 @@
+use serde::{Deserialize, Serialize};
+
+// Const for the player's starting (and maximum) health
+const MAX_HEALTH: u32 = 20;
+
+// Const for the most hunger or fatigue the player can build up
+pub const MAX_NEED: u32 = 100;
+
+// Consts for how many ticks it takes to gain a point of hunger or fatigue, stamina slows fatigue
+const HUNGER_TICKS: u32 = 18;
+const FATIGUE_TICKS: u32 = 15;
+
+// Consts for when hunger or fatigue start to slow the player down
+const NEED_PENALTY_THRESHOLD: u32 = 50;
+const NEED_SEVERE_THRESHOLD: u32 = 80;
+
+// Struct for the player's condition
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Condition {
+    pub health: u32,
+    pub max_health: u32,
+    pub ailments: Vec<Ailment>,
+    #[serde(default)]
+    pub hunger: u32,
+    #[serde(default)]
+    pub fatigue: u32,
+    #[serde(default)]
+    pub needs_tick: Option<u32>,
+}
+
+// Functions for condition
+impl Condition {
+    // Create a new condition, in full health
+    pub fn new() -> Self {
+        Self {
+            health: MAX_HEALTH,
+            max_health: MAX_HEALTH,
+            ailments: Vec::new(),
+            hunger: 0,
+            fatigue: 0,
+            needs_tick: None,
+        }
+    }
+
+    // Take damage, returns true if the player has collapsed
+    pub fn damage(&mut self, amount: u32) -> bool {
+        self.health = self.health.saturating_sub(amount);
+        self.health == 0
+    }
+
+    // Heal by an amount, up to max health
+    pub fn heal(&mut self, amount: u32) {
+        self.health = (self.health + amount).min(self.max_health);
+    }
+
+    // Catch an ailment, a chill left untreated turns into a fever
+    pub fn afflict(&mut self, ailment: Ailment) {
+        if ailment == Ailment::Chill && self.ailments.contains(&Ailment::Chill) {
+            self.ailments.retain(|a| *a != Ailment::Chill);
+            self.afflict(Ailment::Fever);
+        } else if !self.ailments.contains(&ailment) {
+            self.ailments.push(ailment);
+        }
+    }
+
+    // Cure an ailment, returns false if the player didn't have it
+    pub fn cure(&mut self, ailment: &Ailment) -> bool {
+        let had_ailment = self.ailments.contains(ailment);
+        self.ailments.retain(|a| a != ailment);
+        had_ailment
+    }
+
+    // A night's rest heals half your health, or only a little while ailing
+    pub fn rest(&mut self) {
+        if self.ailments.is_empty() {
+            self.heal(self.max_health / 2);
+        } else {
+            self.heal(self.max_health / 10);
+        }
+    }
+
+    // Build up hunger and fatigue for the time passed since they were last updated, ticks are
+    // counted from the start of the game
+    pub fn pass_time(&mut self, total_ticks: u32, stamina: u32) {
+        let last_tick = match self.needs_tick {
+            Some(last_tick) if last_tick <= total_ticks => last_tick,
+            // Nothing to catch up on for a fresh game or an older save
+            _ => total_ticks,
+        };
+
+        let fatigue_ticks = FATIGUE_TICKS + stamina;
+
+        self.hunger =
+            (self.hunger + total_ticks / HUNGER_TICKS - last_tick / HUNGER_TICKS).min(MAX_NEED);
+        self.fatigue =
+            (self.fatigue + total_ticks / fatigue_ticks - last_tick / fatigue_ticks).min(MAX_NEED);
+        self.needs_tick = Some(total_ticks);
+    }
+
+    // Tire from exertion, such as walking the roads
+    pub fn exert(&mut self, amount: u32) {
+        self.fatigue = (self.fatigue + amount).min(MAX_NEED);
+    }
+
+    // Eat something, reducing hunger
+    pub fn eat(&mut self, amount: u32) {
+        self.hunger = self.hunger.saturating_sub(amount);
+    }
+
+    // Catch your breath, reducing fatigue
+    pub fn refresh(&mut self, amount: u32) {
+        self.fatigue = self.fatigue.saturating_sub(amount);
+    }
+
+    // Sleep until a given tick, fatigue is gone and hunger doesn't build while asleep
+    pub fn sleep(&mut self, total_ticks: u32) {
+        self.fatigue = 0;
+        self.needs_tick = Some(total_ticks);
+    }
+
+    // How badly hunger and fatigue are slowing the player down, 0 if not at all
+    pub fn penalty(&self) -> u32 {
+        [self.hunger, self.fatigue]
+            .iter()
+            .map(|need| match *need {
+                need if need >= NEED_SEVERE_THRESHOLD => 2,
+                need if need >= NEED_PENALTY_THRESHOLD => 1,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    // Description of hunger used on the Stats screen, e.g. "Hungry (55)"
+    pub fn describe_hunger(&self) -> String {
+        let label = match self.hunger {
+            0..=24 => "Fed",
+            25..=49 => "Peckish",
+            50..=79 => "Hungry",
+            _ => "Starving",
+        };
+
+        format!("{} ({})", label, self.hunger)
+    }
+
+    // Description of fatigue used on the Stats screen, e.g. "Tired (60)"
+    pub fn describe_fatigue(&self) -> String {
+        let label = match self.fatigue {
+            0..=24 => "Rested",
+            25..=49 => "Weary",
+            50..=79 => "Tired",
+            _ => "Exhausted",
+        };
+
+        format!("{} ({})", label, self.fatigue)
+    }
+
+    // Description used on the Stats screen, e.g. "14/20 (Chill, Injury)"
+    pub fn describe(&self) -> String {
+        if self.ailments.is_empty() {
+            format!("{}/{}", self.health, self.max_health)
+        } else {
+            let ailments: Vec<String> = self
+                .ailments
+                .iter()
+                .map(|ailment| format!("{:?}", ailment))
+                .collect();
+            format!(
+                "{}/{} ({})",
+                self.health,
+                self.max_health,
+                ailments.join(", ")
+            )
+        }
+    }
+}
+
+impl Default for Condition {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Enum for ailments
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Ailment {
+    Chill,
+    Fever,
+    Injury,
+}
+
+// Struct for a temporary blessing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Blessing {
+    pub blessing_type: BlessingType,
+    pub expires_day: u32,
+}
+
+// Enum for blessing types
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum BlessingType {
+    SafeTravel,
+    Fortune,
+}
+
+// Functions for blessing types
+impl BlessingType {
+    // Name shown to the player
+    pub fn name(&self) -> &str {
+        match self {
+            BlessingType::SafeTravel => "Blessing of Safe Travel",
+            BlessingType::Fortune => "Blessing of Fortune",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn needs_build_up_a_point_every_few_ticks() {
+        let mut condition = Condition::new();
+
+        // Nothing to catch up on the first time
+        let start = HUNGER_TICKS * 50;
+        condition.pass_time(start, 0);
+        assert_eq!((condition.hunger, condition.fatigue), (0, 0));
+
+        condition.pass_time(start + HUNGER_TICKS - 1, 0);
+        assert_eq!(condition.hunger, 0);
+
+        condition.pass_time(start + HUNGER_TICKS, 0);
+        assert_eq!(condition.hunger, 1);
+    }
+
+    #[test]
+    fn small_steps_add_up_the_same_as_one_big_one() {
+        let mut stepped = Condition::new();
+        let mut jumped = Condition::new();
+        stepped.pass_time(0, 3);
+        jumped.pass_time(0, 3);
+
+        for total_ticks in (7..=700).step_by(7) {
+            stepped.pass_time(total_ticks, 3);
+        }
+        jumped.pass_time(700, 3);
+
+        assert_eq!(stepped.hunger, jumped.hunger);
+        assert_eq!(stepped.fatigue, jumped.fatigue);
+        assert_eq!(jumped.fatigue, 700 / (FATIGUE_TICKS + 3));
+    }
+
+    #[test]
+    fn stamina_slows_fatigue_and_needs_stop_at_the_maximum() {
+        let mut hardy = Condition::new();
+        let mut frail = Condition::new();
+        hardy.pass_time(0, 10);
+        frail.pass_time(0, 0);
+
+        hardy.pass_time(300, 10);
+        frail.pass_time(300, 0);
+        assert!(hardy.fatigue < frail.fatigue);
+
+        frail.pass_time(100_000, 0);
+        assert_eq!((frail.hunger, frail.fatigue), (MAX_NEED, MAX_NEED));
+
+        // Time going backwards, such as loading an older save, doesn't add anything
+        frail.eat(MAX_NEED);
+        frail.pass_time(50, 0);
+        assert_eq!(frail.hunger, 0);
+    }
+
+    #[test]
+    fn penalties_start_at_the_thresholds() {
+        let mut condition = Condition::new();
+        let penalty = |condition: &mut Condition, hunger, fatigue| {
+            condition.hunger = hunger;
+            condition.fatigue = fatigue;
+            condition.penalty()
+        };
+
+        assert_eq!(penalty(&mut condition, NEED_PENALTY_THRESHOLD - 1, 0), 0);
+        assert_eq!(penalty(&mut condition, NEED_PENALTY_THRESHOLD, 0), 1);
+        assert_eq!(penalty(&mut condition, NEED_SEVERE_THRESHOLD, 0), 2);
+        assert_eq!(
+            penalty(
+                &mut condition,
+                NEED_SEVERE_THRESHOLD,
+                NEED_PENALTY_THRESHOLD
+            ),
+            3
+        );
+        assert_eq!(penalty(&mut condition, MAX_NEED, MAX_NEED), 4);
+    }
+}
@@ -0,0 +1,227 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+// Consts for inventory capacity
+pub const MAX_WEIGHT: u32 = 50;
+const MAX_SLOTS: usize = 20;
+
+// Struct for an inventory
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Inventory {
+    pub slots: Vec<InventorySlot>,
+    pub max_weight: u32,
+    pub max_slots: usize,
+}
+
+// Functions for inventory
+impl Inventory {
+    // Create a new, empty inventory
+    pub fn new() -> Self {
+        Self {
+            slots: Vec::new(),
+            max_weight: MAX_WEIGHT,
+            max_slots: MAX_SLOTS,
+        }
+    }
+
+    // Total weight of everything carried
+    pub fn total_weight(&self) -> u32 {
+        self.slots
+            .iter()
+            .map(|slot| slot.item.weight * slot.quantity)
+            .sum()
+    }
+
+    // Check an item can be added without going over capacity
+    pub fn can_add(&self, item: &super::item::Item, quantity: u32) -> Result<(), InventoryError> {
+        if self.total_weight() + item.weight * quantity > self.max_weight {
+            return Err(InventoryError::TooHeavy);
+        }
+
+        let slots_needed = if item.stackable {
+            if self.slots.iter().any(|slot| slot.item.id == item.id) {
+                0
+            } else {
+                1
+            }
+        } else {
+            quantity as usize
+        };
+
+        if self.slots.len() + slots_needed > self.max_slots {
+            return Err(InventoryError::NoSpace);
+        }
+
+        Ok(())
+    }
+
+    // Add an item, stackable items share a slot and others take one slot each
+    pub fn add(&mut self, item: super::item::Item, quantity: u32) -> Result<(), InventoryError> {
+        self.can_add(&item, quantity)?;
+
+        if item.stackable {
+            if let Some(slot) = self.slots.iter_mut().find(|slot| slot.item.id == item.id) {
+                slot.quantity += quantity;
+            } else {
+                self.slots.push(InventorySlot { item, quantity });
+            }
+        } else {
+            for _ in 0..quantity {
+                self.slots.push(InventorySlot {
+                    item: item.clone(),
+                    quantity: 1,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    // Remove a quantity from the slot at index, returns what was removed
+    pub fn remove(&mut self, index: usize, quantity: u32) -> Option<InventorySlot> {
+        let slot = self.slots.get_mut(index)?;
+        let removed = quantity.min(slot.quantity);
+
+        slot.quantity -= removed;
+        let item = slot.item.clone();
+
+        if slot.quantity == 0 {
+            self.slots.remove(index);
+        }
+
+        Some(InventorySlot {
+            item,
+            quantity: removed,
+        })
+    }
+
+    // Remove a quantity of an item by ID, returns false if there aren't enough
+    pub fn remove_by_id(&mut self, item_id: u32, quantity: u32) -> bool {
+        if self.count(item_id) < quantity {
+            return false;
+        }
+
+        let mut remaining = quantity;
+
+        while remaining > 0 {
+            match self.slots.iter().position(|slot| slot.item.id == item_id) {
+                Some(index) => {
+                    if let Some(removed) = self.remove(index, remaining) {
+                        remaining -= removed.quantity;
+                    }
+                }
+                None => break,
+            }
+        }
+
+        true
+    }
+
+    // Count how many of an item are carried
+    pub fn count(&self, item_id: u32) -> u32 {
+        self.slots
+            .iter()
+            .filter(|slot| slot.item.id == item_id)
+            .map(|slot| slot.quantity)
+            .sum()
+    }
+}
+
+impl Default for Inventory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Struct for an inventory slot
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InventorySlot {
+    pub item: super::item::Item,
+    pub quantity: u32,
+}
+
+// Functions for inventory slot
+impl InventorySlot {
+    // Label used in menus, e.g. "Bread x2"
+    pub fn label(&self) -> String {
+        if self.quantity > 1 {
+            format!("{} x{}", self.item.name, self.quantity)
+        } else {
+            self.item.name.clone()
+        }
+    }
+}
+
+// Enum for inventory errors
+#[derive(Debug)]
+pub enum InventoryError {
+    TooHeavy,
+    NoSpace,
+}
+
+impl fmt::Display for InventoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InventoryError::TooHeavy => write!(f, "That would be too heavy to carry."),
+            InventoryError::NoSpace => write!(f, "There's no room left in your pack."),
+        }
+    }
+}
+
+impl std::error::Error for InventoryError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::item::Item;
+
+    #[test]
+    fn items_over_the_weight_limit_are_refused() {
+        let mut inventory = Inventory::new();
+        let rope = Item::from_id(11).unwrap();
+
+        assert!(inventory.add(rope.clone(), 16).is_ok());
+        assert!(matches!(
+            inventory.add(rope, 1),
+            Err(InventoryError::TooHeavy)
+        ));
+        assert_eq!(inventory.total_weight(), 48);
+    }
+
+    #[test]
+    fn unstackable_items_take_a_slot_each() {
+        let mut inventory = Inventory::new();
+        let ring = Item::from_id(14).unwrap();
+
+        assert!(inventory.add(ring.clone(), MAX_SLOTS as u32).is_ok());
+        assert!(matches!(
+            inventory.add(ring, 1),
+            Err(InventoryError::NoSpace)
+        ));
+        assert_eq!(inventory.slots.len(), MAX_SLOTS);
+    }
+
+    #[test]
+    fn stackable_items_share_a_slot() {
+        let mut inventory = Inventory::new();
+        let lockpick = Item::from_id(crate::entities::item::ITEM_LOCKPICK).unwrap();
+
+        inventory.add(lockpick.clone(), 3).unwrap();
+        inventory.add(lockpick, 4).unwrap();
+
+        assert_eq!(inventory.slots.len(), 1);
+        assert_eq!(inventory.count(crate::entities::item::ITEM_LOCKPICK), 7);
+    }
+
+    #[test]
+    fn remove_by_id_takes_from_several_slots() {
+        let mut inventory = Inventory::new();
+        let ring = Item::from_id(14).unwrap();
+
+        inventory.add(ring, 3).unwrap();
+
+        assert!(!inventory.remove_by_id(14, 4));
+        assert!(inventory.remove_by_id(14, 2));
+        assert_eq!(inventory.count(14), 1);
+    }
+}
@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+
+// Const for the item catalogue (id, name, category, weight, value, stackable)
+const ITEM_CATALOGUE: [(u32, &str, ItemCategory, u32, u32, bool); 19] = [
+    (1, "Bread", ItemCategory::Food, 1, 2, true),
+    (2, "Cheese", ItemCategory::Food, 1, 3, true),
+    (3, "Apple", ItemCategory::Food, 1, 1, true),
+    (4, "Ale", ItemCategory::Drink, 2, 2, true),
+    (5, "Wine", ItemCategory::Drink, 2, 6, true),
+    (6, "Water", ItemCategory::Drink, 2, 1, true),
+    (7, "Candle", ItemCategory::Religious, 1, 1, true),
+    (8, "Holy Water", ItemCategory::Religious, 1, 10, true),
+    (9, "Prayer Beads", ItemCategory::Religious, 1, 8, false),
+    (10, "Lockpick", ItemCategory::Tool, 0, 5, true),
+    (11, "Rope", ItemCategory::Tool, 3, 4, false),
+    (12, "Lantern", ItemCategory::Tool, 3, 12, false),
+    (13, "Dagger", ItemCategory::Weapon, 2, 15, false),
+    (14, "Gold Ring", ItemCategory::Valuable, 0, 50, false),
+    (15, "Silver Necklace", ItemCategory::Valuable, 0, 35, false),
+    (16, "Bandage", ItemCategory::Medicine, 0, 4, true),
+    (17, "Healing Potion", ItemCategory::Medicine, 1, 25, true),
+    (18, "Cloth", ItemCategory::Material, 1, 3, true),
+    (19, "Nails", ItemCategory::Material, 1, 1, true),
+];
+
+// Consts for item IDs referenced directly by game logic
+pub const ITEM_BREAD: u32 = 1;
+pub const ITEM_ALE: u32 = 4;
+pub const ITEM_WATER: u32 = 6;
+pub const ITEM_LOCKPICK: u32 = 10;
+pub const ITEM_BANDAGE: u32 = 16;
+pub const ITEM_HEALING_POTION: u32 = 17;
+
+// Struct for an item
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Item {
+    pub id: u32,
+    pub name: String,
+    pub category: ItemCategory,
+    pub weight: u32,
+    pub value: u32,
+    pub stackable: bool,
+}
+
+// Functions for item
+impl Item {
+    // Create an item from the catalogue by ID
+    pub fn from_id(id: u32) -> Option<Self> {
+        ITEM_CATALOGUE
+            .iter()
+            .find(|(item_id, ..)| *item_id == id)
+            .map(|(id, name, category, weight, value, stackable)| Self {
+                id: *id,
+                name: name.to_string(),
+                category: category.clone(),
+                weight: *weight,
+                value: *value,
+                stackable: *stackable,
+            })
+    }
+}
+
+// Enum for item categories
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ItemCategory {
+    Food,
+    Drink,
+    Religious,
+    Tool,
+    Weapon,
+    Valuable,
+    Medicine,
+    Material,
+}
@@ -1 +1,6 @@
+pub mod attributes;
+pub mod condition;
+pub mod inventory;
+pub mod item;
 pub mod player;
+pub mod progression;
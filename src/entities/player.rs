@@ -1,4 +1,8 @@
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+// Const for how far reputation and disposition can go either way
+pub const STANDING_LIMIT: i32 = 20;
 
 // Struct for player
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -8,6 +12,36 @@ pub struct Player {
     pub current_town_id: u32,
     pub current_building_id: Option<u32>,
     pub current_room_id: Option<u32>,
+    #[serde(default)]
+    pub gold: u32,
+    #[serde(default)]
+    pub inventory: super::inventory::Inventory,
+    #[serde(default)]
+    pub bounties: HashMap<u32, u32>,
+    #[serde(default)]
+    pub attributes: super::attributes::Attributes,
+    #[serde(default)]
+    pub progression: super::progression::Progression,
+    #[serde(default)]
+    pub visited_towns: HashSet<u32>,
+    #[serde(default)]
+    pub visited_buildings: HashSet<u32>,
+    #[serde(default)]
+    pub visited_rooms: HashSet<u32>,
+    #[serde(default)]
+    pub condition: super::condition::Condition,
+    #[serde(default)]
+    pub blessings: Vec<super::condition::Blessing>,
+    #[serde(default)]
+    pub town_reputation: HashMap<u32, i32>,
+    #[serde(default)]
+    pub respawn_building_id: Option<u32>,
+    #[serde(default)]
+    pub npc_notes: HashMap<u32, Vec<String>>,
+    #[serde(default)]
+    pub npc_disposition: HashMap<u32, i32>,
+    #[serde(default)]
+    pub journal: Vec<crate::world::quest::QuestLog>,
 }
 
 // Functions for player
@@ -26,6 +60,142 @@ impl Player {
             current_town_id,
             current_building_id,
             current_room_id,
+            gold: 0,
+            inventory: super::inventory::Inventory::new(),
+            bounties: HashMap::new(),
+            attributes: super::attributes::Attributes::new(),
+            progression: super::progression::Progression::new(),
+            visited_towns: HashSet::new(),
+            visited_buildings: HashSet::new(),
+            visited_rooms: HashSet::new(),
+            condition: super::condition::Condition::new(),
+            blessings: Vec::new(),
+            town_reputation: HashMap::new(),
+            respawn_building_id: None,
+            npc_notes: HashMap::new(),
+            npc_disposition: HashMap::new(),
+            journal: Vec::new(),
+        }
+    }
+
+    // Check whether a blessing is active on a day
+    pub fn is_blessed(&self, blessing_type: &super::condition::BlessingType, day: u32) -> bool {
+        self.blessings
+            .iter()
+            .any(|blessing| blessing.blessing_type == *blessing_type && blessing.expires_day > day)
+    }
+
+    // Standing in a town
+    pub fn reputation(&self, town_id: u32) -> i32 {
+        self.town_reputation.get(&town_id).copied().unwrap_or(0)
+    }
+
+    // How an NPC feels about the player
+    pub fn disposition(&self, npc_id: u32) -> i32 {
+        self.npc_disposition.get(&npc_id).copied().unwrap_or(0)
+    }
+
+    // Change standing in a town, kept within the limit
+    pub fn change_reputation(&mut self, town_id: u32, amount: i32) {
+        let reputation = self.town_reputation.entry(town_id).or_insert(0);
+        *reputation = (*reputation + amount).clamp(-STANDING_LIMIT, STANDING_LIMIT);
+    }
+
+    // Change how an NPC feels about the player, kept within the limit
+    pub fn change_disposition(&mut self, npc_id: u32, amount: i32) {
+        let disposition = self.npc_disposition.entry(npc_id).or_insert(0);
+        *disposition = (*disposition + amount).clamp(-STANDING_LIMIT, STANDING_LIMIT);
+    }
+
+    // Gain experience, returns a note if it was enough to level up
+    pub fn gain_experience(&mut self, amount: u32) -> Option<String> {
+        if self.progression.gain(amount) > 0 {
+            Some(format!(
+                "You reach level {}! Check your Character screen.",
+                self.progression.level
+            ))
+        } else {
+            None
         }
     }
+
+    // Status of a quest, taken from the journal
+    pub fn quest_status(&self, quest_id: &str) -> QuestStatus {
+        match self.journal.iter().find(|log| log.quest_id == quest_id) {
+            Some(log) if log.is_complete() => QuestStatus::Completed,
+            Some(_) => QuestStatus::Active,
+            None => QuestStatus::NotStarted,
+        }
+    }
+
+    // Remember something learned about an NPC, returns false if it was already known
+    pub fn learn_fact(&mut self, npc_id: u32, fact: String) -> bool {
+        let notes = self.npc_notes.entry(npc_id).or_default();
+
+        if notes.contains(&fact) {
+            false
+        } else {
+            notes.push(fact);
+            true
+        }
+    }
+}
+
+// Enum for quest status
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum QuestStatus {
+    NotStarted,
+    Active,
+    Completed,
+}
+
+// Describe a reputation or disposition
+pub fn standing_label(standing: i32) -> &'static str {
+    match standing {
+        ..=-10 => "Hated",
+        -9..=-3 => "Disliked",
+        -2..=2 => "Neutral",
+        3..=9 => "Liked",
+        _ => "Respected",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn standing_is_kept_within_the_limit() {
+        let mut player = Player::new(1, "Bob".into(), 1, None, None);
+
+        player.change_reputation(1, 15);
+        player.change_reputation(1, 15);
+        assert_eq!(player.reputation(1), STANDING_LIMIT);
+        assert_eq!(player.reputation(2), 0);
+
+        player.change_disposition(4, -50);
+        assert_eq!(player.disposition(4), -STANDING_LIMIT);
+    }
+
+    #[test]
+    fn standing_labels_cover_each_band() {
+        let labels: Vec<&str> = [-10, -9, -3, -2, 2, 3, 9, 10]
+            .into_iter()
+            .map(standing_label)
+            .collect();
+
+        assert_eq!(
+            labels,
+            [
+                "Hated",
+                "Disliked",
+                "Disliked",
+                "Neutral",
+                "Neutral",
+                "Liked",
+                "Liked",
+                "Respected"
+            ]
+        );
+    }
 }
@@ -0,0 +1,208 @@
+use serde::{Deserialize, Serialize};
+
+// Const for the experience needed to go from level 1 to 2, each level after takes a step more
+const EXPERIENCE_PER_LEVEL: u32 = 100;
+
+// Const for the highest an attribute can be raised
+pub const MAX_ATTRIBUTE: u32 = 10;
+
+// Const for the highest rank of a skill
+pub const MAX_SKILL_RANK: u32 = 3;
+
+// Const for the levels between skill ranks, rank 2 needs level 3 and rank 3 needs level 5
+const LEVELS_PER_RANK: u32 = 2;
+
+// Struct for the player's experience, level and skills
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Progression {
+    pub experience: u32,
+    pub level: u32,
+    pub attribute_points: u32,
+    pub skill_points: u32,
+    pub haggling: u32,
+    pub lockpicking: u32,
+    pub pathfinding: u32,
+}
+
+// Functions for progression
+impl Progression {
+    // Create a new progression, at level 1 with nothing learned
+    pub fn new() -> Self {
+        Self {
+            experience: 0,
+            level: 1,
+            attribute_points: 0,
+            skill_points: 0,
+            haggling: 0,
+            lockpicking: 0,
+            pathfinding: 0,
+        }
+    }
+
+    // Total experience needed to reach the next level
+    pub fn next_level_experience(&self) -> u32 {
+        EXPERIENCE_PER_LEVEL * self.level * (self.level + 1) / 2
+    }
+
+    // Gain experience, returns the number of levels gained
+    pub fn gain(&mut self, amount: u32) -> u32 {
+        self.experience += amount;
+
+        let mut levels = 0;
+        while self.experience >= self.next_level_experience() {
+            self.level += 1;
+            self.attribute_points += 1;
+            self.skill_points += 1;
+            levels += 1;
+        }
+
+        levels
+    }
+
+    // Current rank in a skill
+    pub fn rank(&self, skill: &Skill) -> u32 {
+        match skill {
+            Skill::Haggling => self.haggling,
+            Skill::Lockpicking => self.lockpicking,
+            Skill::Pathfinding => self.pathfinding,
+        }
+    }
+
+    // Level needed to learn the next rank in a skill
+    pub fn required_level(&self, skill: &Skill) -> u32 {
+        self.rank(skill) * LEVELS_PER_RANK + 1
+    }
+
+    // Learn the next rank in a skill, returns an error message if it can't be learned
+    pub fn learn(&mut self, skill: &Skill) -> Result<u32, String> {
+        let rank = self.rank(skill);
+
+        if rank >= MAX_SKILL_RANK {
+            return Err(format!("You've mastered {}.", skill.name()));
+        }
+
+        if self.skill_points == 0 {
+            return Err("You have no skill points to spend.".into());
+        }
+
+        if self.level < self.required_level(skill) {
+            return Err(format!(
+                "You need to reach level {} first.",
+                self.required_level(skill)
+            ));
+        }
+
+        self.skill_points -= 1;
+
+        let rank = match skill {
+            Skill::Haggling => &mut self.haggling,
+            Skill::Lockpicking => &mut self.lockpicking,
+            Skill::Pathfinding => &mut self.pathfinding,
+        };
+        *rank += 1;
+
+        Ok(*rank)
+    }
+
+    // Description used on the Stats screen, e.g. "2 (150/300 XP)"
+    pub fn describe(&self) -> String {
+        format!(
+            "{} ({}/{} XP)",
+            self.level,
+            self.experience,
+            self.next_level_experience()
+        )
+    }
+}
+
+impl Default for Progression {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Enum for skills
+#[derive(Debug, Clone, PartialEq)]
+pub enum Skill {
+    Haggling,
+    Lockpicking,
+    Pathfinding,
+}
+
+// Functions for skills
+impl Skill {
+    // Every skill, in the order shown on the Character screen
+    pub const ALL: [Skill; 3] = [Skill::Haggling, Skill::Lockpicking, Skill::Pathfinding];
+
+    // Name shown to the player
+    pub fn name(&self) -> &str {
+        match self {
+            Skill::Haggling => "Haggling",
+            Skill::Lockpicking => "Lockpicking",
+            Skill::Pathfinding => "Pathfinding",
+        }
+    }
+
+    // What each rank of the skill does
+    pub fn effect(&self) -> &str {
+        match self {
+            Skill::Haggling => "better prices in shops and taverns",
+            Skill::Lockpicking => "a steadier hand on locks",
+            Skill::Pathfinding => "shorter journeys on foot",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn each_level_takes_a_step_more_experience() {
+        let mut progression = Progression::new();
+
+        assert_eq!(progression.gain(99), 0);
+        assert_eq!(progression.level, 1);
+
+        assert_eq!(progression.gain(1), 1);
+        assert_eq!(progression.level, 2);
+        assert_eq!(progression.next_level_experience(), 300);
+
+        // Enough for two levels at once, with a point of each to spend per level
+        assert_eq!(progression.gain(500), 2);
+        assert_eq!(progression.level, 4);
+        assert_eq!(progression.describe(), "4 (600/1000 XP)");
+        assert_eq!(
+            (progression.attribute_points, progression.skill_points),
+            (3, 3)
+        );
+    }
+
+    #[test]
+    fn skill_ranks_need_points_and_levels() {
+        let mut progression = Progression::new();
+        assert_eq!(
+            progression.learn(&Skill::Haggling),
+            Err("You have no skill points to spend.".into())
+        );
+
+        // Level 2 is enough for the first rank but not the second
+        progression.gain(100);
+        progression.skill_points = 2;
+        assert_eq!(progression.learn(&Skill::Haggling), Ok(1));
+        assert_eq!(
+            progression.learn(&Skill::Haggling),
+            Err("You need to reach level 3 first.".into())
+        );
+
+        progression.level = 5;
+        progression.skill_points = 5;
+        assert_eq!(progression.learn(&Skill::Haggling), Ok(2));
+        assert_eq!(progression.learn(&Skill::Haggling), Ok(3));
+        assert_eq!(
+            progression.learn(&Skill::Haggling),
+            Err("You've mastered Haggling.".into())
+        );
+        assert_eq!(progression.skill_points, 3);
+    }
+}
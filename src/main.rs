@@ -11,11 +11,20 @@ mod world;
 
 // Main function
 fn main() {
+    // Parse command line arguments
+    let seed_override = match parse_seed(std::env::args().skip(1)) {
+        Ok(seed) => seed,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
     // Initialise logging
     match init_logger() {
         Ok(()) => {
             // Starts Ratatui and in turn the main loop
-            match ui::display::start() {
+            match ui::display::start(seed_override) {
                 Ok(()) => {
                     process::exit(0);
                 }
@@ -44,3 +53,32 @@ fn init_logger() -> Result<(), std::io::Error> {
 
     Ok(())
 }
+
+// Parses the optional --seed argument used to reproduce a playthrough
+fn parse_seed(mut args: impl Iterator<Item = String>) -> Result<Option<u64>, String> {
+    let mut seed = None;
+
+    while let Some(arg) = args.next() {
+        if arg == "--seed" {
+            let value = args
+                .next()
+                .ok_or_else(|| "Missing value for --seed".to_string())?;
+
+            seed = Some(
+                value
+                    .parse::<u64>()
+                    .map_err(|e| format!("Invalid value for --seed: {} ({})", value, e))?,
+            );
+        } else if let Some(value) = arg.strip_prefix("--seed=") {
+            seed = Some(
+                value
+                    .parse::<u64>()
+                    .map_err(|e| format!("Invalid value for --seed: {} ({})", value, e))?,
+            );
+        } else {
+            return Err(format!("Unknown argument: {}", arg));
+        }
+    }
+
+    Ok(seed)
+}
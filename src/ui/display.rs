@@ -1,8 +1,34 @@
 use color_eyre::Result;
+use crossterm::event::{DisableMouseCapture, EnableMouseCapture};
+use crossterm::execute;
 use ratatui::layout::{Alignment, Constraint, Direction, Layout};
 use ratatui::prelude::Rect;
-use ratatui::widgets::{Block, BorderType, Borders, Clear, List, Paragraph};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, BorderType, Borders, Clear, List, Paragraph, Wrap};
 use ratatui::{DefaultTerminal, Frame};
+use std::io::stdout;
+
+// Consts for the smallest terminal the game can be played in
+const MIN_WIDTH: u16 = 40;
+const MIN_HEIGHT: u16 = 12;
+
+// Consts for layout breakpoints, narrower terminals get a single column and wider ones three
+const COMPACT_WIDTH: u16 = 80;
+const WIDE_WIDTH: u16 = 160;
+
+// Consts for the heights of the stats panel and the message log, the stats give way on short
+// terminals so the log always keeps a few rows
+const STATS_HEIGHT: u16 = 19;
+const COMPACT_MESSAGES_HEIGHT: u16 = 5;
+const MIN_MESSAGES_HEIGHT: u16 = 5;
+
+// Consts for the smallest a popup can be
+const MIN_POPUP_WIDTH: u16 = 30;
+const MIN_POPUP_HEIGHT: u16 = 7;
+
+// Consts for the smallest the Help overlay can be
+const MIN_HELP_WIDTH: u16 = 40;
+const MIN_HELP_HEIGHT: u16 = 12;
 
 // Struct for Managers
 pub struct Managers {
@@ -11,6 +37,12 @@ pub struct Managers {
     pub time_manager: crate::world::time::TimeManager,
     pub weather_manager: crate::world::weather::WeatherManager,
     pub save_manager: crate::core::save::SaveGameManager,
+    pub rng_manager: crate::core::rng::RngManager,
+    pub dialogue_manager: crate::world::dialogue::DialogueManager,
+    pub quest_manager: crate::world::quest::QuestManager,
+    pub records_manager: crate::core::records::RecordsManager,
+    pub config_manager: crate::core::config::ConfigManager,
+    pub message_manager: crate::core::messages::MessageManager,
 }
 
 // Struct for UI Components
@@ -19,22 +51,27 @@ pub struct UIComponents {
     pub viewport: super::viewport::Viewport,
     stats: super::stats::Stats,
     pub popup: super::popup::Popup,
+    pub messages: super::messages::Messages,
+    pub help: super::help::Help,
+    pub show_stats: bool,
 }
 
 // Starts Ratatui and launches the main loop with run()
 // Restores original terminal when main loop in run() finishes
-pub fn start() -> Result<()> {
+pub fn start(seed_override: Option<u64>) -> Result<()> {
     color_eyre::install()?;
     let terminal = ratatui::init();
+    execute!(stdout(), EnableMouseCapture)?;
 
-    let result = run(terminal);
+    let result = run(terminal, seed_override);
 
+    execute!(stdout(), DisableMouseCapture)?;
     ratatui::restore();
     result
 }
 
 // Main loop
-fn run(mut terminal: DefaultTerminal) -> Result<()> {
+fn run(mut terminal: DefaultTerminal, seed_override: Option<u64>) -> Result<()> {
     // Create managers
     let mut managers = Managers {
         state_manager: crate::core::states::StateManager::new(),
@@ -42,6 +79,12 @@ fn run(mut terminal: DefaultTerminal) -> Result<()> {
         time_manager: crate::world::time::TimeManager::new(),
         weather_manager: crate::world::weather::WeatherManager::new(),
         save_manager: crate::core::save::SaveGameManager::new(),
+        rng_manager: crate::core::rng::RngManager::new(seed_override),
+        dialogue_manager: crate::world::dialogue::DialogueManager::new(),
+        quest_manager: crate::world::quest::QuestManager::new(),
+        records_manager: crate::core::records::RecordsManager::new(),
+        config_manager: crate::core::config::ConfigManager::new(),
+        message_manager: crate::core::messages::MessageManager::new(),
     };
 
     // Create UI components
@@ -50,9 +93,33 @@ fn run(mut terminal: DefaultTerminal) -> Result<()> {
         viewport: super::viewport::Viewport::new(),
         stats: super::stats::Stats::new(),
         popup: super::popup::Popup::new(),
+        messages: super::messages::Messages::new(),
+        help: super::help::Help::new(),
+        show_stats: false,
     };
 
     loop {
+        // Hunger and fatigue build up as time passes
+        managers
+            .world_manager
+            .update_needs(managers.time_manager.now());
+
+        // Keep the records up to date and announce any achievements
+        let weather = managers
+            .weather_manager
+            .weather_arc_rwlock
+            .as_ref()
+            .and_then(|game_weather| game_weather.read().ok().map(|w| w.weather_type.clone()));
+        managers.records_manager.update(
+            &managers.world_manager,
+            managers.time_manager.now(),
+            weather.clone(),
+        );
+        managers
+            .message_manager
+            .update_weather(managers.time_manager.now(), weather);
+        crate::core::events::announce_achievement(&mut managers, &mut ui_components);
+
         // Update
         ui_components.menu.update(&managers);
         ui_components.viewport.update(&managers);
@@ -66,6 +133,8 @@ fn run(mut terminal: DefaultTerminal) -> Result<()> {
 
         // Handle events
         if !crate::core::events::EventHandler::update(&mut managers, &mut ui_components)? {
+            managers.records_manager.flush();
+
             break Ok(());
         }
     }
@@ -75,71 +144,275 @@ fn run(mut terminal: DefaultTerminal) -> Result<()> {
 fn render(frame: &mut Frame, managers: &Managers, ui_components: &mut UIComponents) {
     // Layout
     let area = frame.area();
+    let theme = &managers.config_manager.theme;
 
-    let vertical = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage(70), // Viewport and menu
-            Constraint::Percentage(30), // Stats
+    if area.width < MIN_WIDTH || area.height < MIN_HEIGHT {
+        // Nothing can be clicked while the game is hidden
+        ui_components.menu.area = Rect::default();
+        ui_components.viewport.area = Rect::default();
+        ui_components.messages.area = Rect::default();
+
+        let too_small = Paragraph::new(vec![
+            Line::from("Terminal too small!"),
+            Line::from(format!("{}x{}", area.width, area.height)),
+            Line::from(format!("Resize to at least {}x{}.", MIN_WIDTH, MIN_HEIGHT)),
         ])
-        .split(area);
+        .style(theme.base)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+        frame.render_widget(too_small, centered_rect(100, 0, 0, 5, area));
+
+        return;
+    }
 
-    let horizontal = Layout::default()
+    // Key hints along the bottom, the rest of the screen is laid out above them
+    let screen = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Percentage(60), // Viewport
-            Constraint::Percentage(40), // Menu
+            Constraint::Min(0),    // Panes
+            Constraint::Length(1), // Footer
         ])
-        .split(vertical[0]);
+        .split(area);
+
+    let areas = layout(screen[0], ui_components.show_stats);
 
     // Menu
-    let menu_area = horizontal[1];
+    let menu_area = areas.menu;
     ui_components.menu.visible_count = menu_area.height.saturating_sub(2) as usize;
+    ui_components.menu.area = menu_area;
 
     let menu_options = ui_components.menu.render(managers);
 
-    let menu_block =
-        List::new(menu_options).block(Block::default().title("Menu").borders(Borders::ALL));
+    let menu_block = List::new(menu_options).block(
+        Block::default()
+            .title(ui_components.menu.title())
+            .borders(Borders::ALL)
+            .style(theme.base)
+            .border_style(theme.border),
+    );
     frame.render_widget(menu_block, menu_area);
 
-    // Viewport
+    // Viewport (scrolls when there's more than fits)
+    let viewport_title = ui_components.viewport.title();
     let viewport_text = ui_components.viewport.render(managers);
 
     let viewport_block = Paragraph::new(viewport_text)
-        .block(Block::default().title("Viewport").borders(Borders::ALL));
-    frame.render_widget(viewport_block, horizontal[0]);
+        .block(
+            Block::default()
+                .title(viewport_title)
+                .borders(Borders::ALL)
+                .style(theme.base)
+                .border_style(theme.border),
+        )
+        .wrap(Wrap { trim: false });
+
+    // Lines wrap on words, so let the paragraph count the rows it needs including its border
+    let max_scroll = viewport_block
+        .line_count(areas.viewport.width.saturating_sub(2))
+        .saturating_sub(areas.viewport.height as usize)
+        .min(u16::MAX as usize) as u16;
+    let scroll = ui_components.viewport.scroll.min(max_scroll);
+
+    frame.render_widget(viewport_block.scroll((scroll, 0)), areas.viewport);
+    ui_components.viewport.area = areas.viewport;
+    ui_components.viewport.scroll = scroll;
+    ui_components.viewport.max_scroll = max_scroll;
 
-    // Stats
-    let stats_text = ui_components.stats.render(managers);
+    // Message Log
+    ui_components.messages.area = areas.messages;
 
-    let stats_block =
-        Paragraph::new(stats_text).block(Block::default().title("Stats").borders(Borders::ALL));
-    frame.render_widget(stats_block, vertical[1]);
+    let messages_text = ui_components.messages.render(managers);
+
+    let messages_block = Paragraph::new(messages_text)
+        .block(
+            Block::default()
+                .title(ui_components.messages.title())
+                .borders(Borders::ALL)
+                .style(theme.base)
+                .border_style(theme.border),
+        )
+        .wrap(Wrap { trim: false });
+    frame.render_widget(messages_block, areas.messages);
+
+    // Stats (hidden in the compact layout unless toggled on)
+    if let Some(stats_area) = areas.stats {
+        let stats_text = ui_components.stats.render(managers);
+
+        let stats_block = Paragraph::new(stats_text).block(
+            Block::default()
+                .title("Stats")
+                .borders(Borders::ALL)
+                .style(theme.base)
+                .border_style(theme.border),
+        );
+        frame.render_widget(stats_block, stats_area);
+    }
+
+    // Footer
+    let footer_text = ui_components
+        .help
+        .render_footer(managers, &ui_components.menu);
+
+    let footer = Paragraph::new(footer_text).style(theme.base);
+    frame.render_widget(footer, screen[1]);
 
     // Popup (if required)
     if ui_components.popup.display {
         let (popup_title, popup_text) = ui_components.popup.render(managers);
 
-        let popup_area = centered_rect(40, 15, area);
+        let popup_area = centered_rect(40, 15, MIN_POPUP_WIDTH, MIN_POPUP_HEIGHT, area);
 
         let popup_block = Paragraph::new(popup_text)
             .block(
                 Block::default()
                     .title(popup_title)
                     .borders(Borders::ALL)
-                    .border_type(BorderType::Rounded),
+                    .border_type(BorderType::Rounded)
+                    .style(theme.base)
+                    .border_style(theme.border),
             )
-            .alignment(Alignment::Center);
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
         frame.render_widget(Clear, popup_area); // Clear area under popup
         frame.render_widget(popup_block, popup_area);
     }
+
+    // Help (shown over everything else)
+    if ui_components.help.display {
+        let help_area = centered_rect(60, 70, MIN_HELP_WIDTH, MIN_HELP_HEIGHT, area);
+
+        let help_block = Paragraph::new(ui_components.help.render(managers, &ui_components.menu))
+            .block(
+                Block::default()
+                    .title("Help")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .style(theme.base)
+                    .border_style(theme.border),
+            )
+            .wrap(Wrap { trim: false });
+        frame.render_widget(Clear, help_area);
+        frame.render_widget(help_block, help_area);
+    }
 }
 
-// Calculate a centered rectangle for popups
-fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
-    let popup_width = area.width * percent_x / 100;
-    let popup_height = area.height * percent_y / 100;
-    let x = (area.width - popup_width) / 2;
-    let y = (area.height - popup_height) / 2;
+// Struct for the areas of the screen each pane is drawn in
+struct Areas {
+    viewport: Rect,
+    menu: Rect,
+    messages: Rect,
+    stats: Option<Rect>,
+}
+
+// Split the terminal into the viewport, menu, message log and stats areas, the arrangement
+// depends on the width
+fn layout(area: Rect, show_stats: bool) -> Areas {
+    if area.width < COMPACT_WIDTH {
+        // Compact, a single column with the stats only shown when toggled on
+        let column = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Percentage(if show_stats { 35 } else { 50 }), // Viewport
+                Constraint::Percentage(if show_stats { 30 } else { 0 }),  // Stats
+                Constraint::Length(COMPACT_MESSAGES_HEIGHT),              // Message Log
+                Constraint::Min(0),                                       // Menu
+            ])
+            .split(area);
+
+        Areas {
+            viewport: column[0],
+            menu: column[3],
+            messages: column[2],
+            stats: show_stats.then_some(column[1]),
+        }
+    } else {
+        let (main, side) = if area.width < WIDE_WIDTH {
+            // Regular, the viewport above the menu with the stats and message log alongside
+            let columns = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([
+                    Constraint::Percentage(70), // Viewport and menu
+                    Constraint::Percentage(30), // Stats and message log
+                ])
+                .split(area);
+
+            let main = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Percentage(60), // Viewport
+                    Constraint::Percentage(40), // Menu
+                ])
+                .split(columns[0]);
+
+            ((main[0], main[1]), columns[1])
+        } else {
+            // Wide, three columns side by side
+            let columns = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([
+                    Constraint::Percentage(45), // Viewport
+                    Constraint::Percentage(25), // Menu
+                    Constraint::Percentage(30), // Stats and message log
+                ])
+                .split(area);
+
+            ((columns[0], columns[1]), columns[2])
+        };
+
+        let side = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Max(STATS_HEIGHT),        // Stats
+                Constraint::Min(MIN_MESSAGES_HEIGHT), // Message Log
+            ])
+            .split(side);
+
+        Areas {
+            viewport: main.0,
+            menu: main.1,
+            messages: side[1],
+            stats: Some(side[0]),
+        }
+    }
+}
+
+// Calculate a centered rectangle for popups, never smaller than the minimum size or larger than the area
+fn centered_rect(
+    percent_x: u16,
+    percent_y: u16,
+    min_width: u16,
+    min_height: u16,
+    area: Rect,
+) -> Rect {
+    let popup_width = ((area.width as u32 * percent_x as u32 / 100) as u16)
+        .max(min_width)
+        .min(area.width);
+    let popup_height = ((area.height as u32 * percent_y as u32 / 100) as u16)
+        .max(min_height)
+        .min(area.height);
+    let x = area.x + (area.width - popup_width) / 2;
+    let y = area.y + (area.height - popup_height) / 2;
     Rect::new(x, y, popup_width, popup_height)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_message_log_keeps_its_rows_on_short_terminals() {
+        for width in [COMPACT_WIDTH, WIDE_WIDTH] {
+            for height in MIN_HEIGHT - 1..=STATS_HEIGHT + MIN_MESSAGES_HEIGHT {
+                let areas = layout(Rect::new(0, 0, width, height), true);
+
+                assert_eq!(areas.messages.height, MIN_MESSAGES_HEIGHT);
+            }
+
+            // Tall enough for all of the stats, the log gets the rest
+            let areas = layout(Rect::new(0, 0, width, 40), true);
+
+            assert_eq!(areas.stats.map(|stats| stats.height), Some(STATS_HEIGHT));
+            assert_eq!(areas.messages.height, 40 - STATS_HEIGHT);
+        }
+    }
+}
@@ -0,0 +1,196 @@
+use crate::core::keymap::Action;
+use crate::core::states::StateType;
+use ratatui::text::{Line, Span};
+
+// Struct for the Help overlay and the key hints footer
+pub struct Help {
+    pub display: bool,
+}
+
+// Functions for Help
+impl Help {
+    // Create a new Help overlay, hidden until asked for
+    pub fn new() -> Self {
+        Self { display: false }
+    }
+
+    // Renders the Help overlay, every action available on the current screen and its keys
+    // Keys that only type characters on the current screen are left out
+    pub fn render(
+        &self,
+        managers: &super::display::Managers,
+        menu: &super::menu::Menu,
+    ) -> Vec<Line<'_>> {
+        let theme = &managers.config_manager.theme;
+        let keymap = &managers.config_manager.keymap;
+        let typing = is_typing(managers, menu);
+
+        let mut lines = vec![Line::from("Keys for this screen:"), Line::from("")];
+
+        lines.extend(
+            available_actions(managers)
+                .iter()
+                .filter(|hint| !keymap.keys(&hint.action, typing).is_empty())
+                .map(|hint| {
+                    Line::from(vec![
+                        Span::styled(
+                            format!("{:>12}", keymap.describe(&hint.action, typing)),
+                            theme.accent,
+                        ),
+                        Span::raw(format!("  {}", hint.label)),
+                    ])
+                }),
+        );
+
+        if let Some(note) = typing_note(&managers.state_manager.current_state) {
+            lines.push(Line::from(""));
+            lines.push(Line::from(note));
+        }
+
+        lines.push(Line::from(""));
+        lines.push(Line::from("Press any key to close."));
+
+        lines
+    }
+
+    // Renders the footer, the first key of the most useful actions on the current screen
+    pub fn render_footer(
+        &self,
+        managers: &super::display::Managers,
+        menu: &super::menu::Menu,
+    ) -> Line<'_> {
+        let theme = &managers.config_manager.theme;
+        let keymap = &managers.config_manager.keymap;
+        let typing = is_typing(managers, menu);
+
+        let spans: Vec<Span> = available_actions(managers)
+            .iter()
+            .filter(|hint| hint.footer)
+            .filter_map(|hint| {
+                let key = keymap.key(&hint.action, typing)?;
+
+                Some(vec![
+                    Span::styled(format!(" {}", key), theme.accent),
+                    Span::styled(format!(" {} ", hint.label), theme.text),
+                ])
+            })
+            .flatten()
+            .collect();
+
+        Line::from(spans)
+    }
+}
+
+// Struct for a hint, an action and what it does on the current screen
+struct Hint {
+    action: Action,
+    label: &'static str,
+    footer: bool,
+}
+
+// Functions for hints
+impl Hint {
+    // Create a hint, footer hints are also shown along the bottom of the screen
+    fn new(action: Action, label: &'static str, footer: bool) -> Self {
+        Self {
+            action,
+            label,
+            footer,
+        }
+    }
+}
+
+// Actions available on the current screen, in the order they're listed
+fn available_actions(managers: &super::display::Managers) -> Vec<Hint> {
+    match managers.state_manager.current_state {
+        // New Game (Enter Name)
+        StateType::Name => vec![
+            Hint::new(Action::Select, "Confirm name", true),
+            Hint::new(Action::Back, "Cancel", true),
+            Hint::new(Action::Help, "Help", true),
+        ],
+        // New Game (Confirm Name)
+        StateType::NameConfirm => vec![
+            Hint::new(Action::Select, "Start the game", true),
+            Hint::new(Action::Back, "Change name", true),
+            Hint::new(Action::Help, "Help", true),
+        ],
+        // Save Game, Load Game (Error), Initialize Game (Error), and Notice
+        StateType::GameSaveSuccess
+        | StateType::GameSaveError
+        | StateType::GameLoadError
+        | StateType::GameInitError
+        | StateType::Notice => vec![Hint::new(Action::Select, "Continue", true)],
+        // Directory
+        StateType::Directory => {
+            let mut hints = menu_actions();
+            hints.extend([
+                Hint::new(Action::ExportLog, "Export log", false),
+                Hint::new(Action::Stats, "Toggle stats", false),
+                Hint::new(Action::Help, "Help", true),
+            ]);
+            hints
+        }
+        // All other states
+        _ => {
+            let mut hints = menu_actions();
+            hints.extend([
+                Hint::new(Action::Filter, "Filter", true),
+                Hint::new(Action::ViewUp, "Scroll view up", false),
+                Hint::new(Action::ViewDown, "Scroll view down", false),
+                Hint::new(Action::LogUp, "Scroll log up", false),
+                Hint::new(Action::LogDown, "Scroll log down", false),
+                Hint::new(Action::ExportLog, "Export log", false),
+                Hint::new(Action::Stats, "Toggle stats", false),
+            ]);
+
+            if crate::core::events::shortcuts_enabled(managers) {
+                hints.extend([
+                    Hint::new(Action::Save, "Save", true),
+                    Hint::new(Action::Map, "Map", true),
+                    Hint::new(Action::Quit, "Quit", true),
+                ]);
+            }
+
+            hints.push(Hint::new(Action::Help, "Help", true));
+            hints
+        }
+    }
+}
+
+// Actions for moving around and choosing from a menu
+fn menu_actions() -> Vec<Hint> {
+    vec![
+        Hint::new(Action::Up, "Up", true),
+        Hint::new(Action::Down, "Down", true),
+        Hint::new(Action::PageUp, "Page up", false),
+        Hint::new(Action::PageDown, "Page down", false),
+        Hint::new(Action::First, "First option", false),
+        Hint::new(Action::Last, "Last option", false),
+        Hint::new(Action::Select, "Select", true),
+        Hint::new(Action::Back, "Back", true),
+    ]
+}
+
+// Whether characters are typed into an input on the current screen instead of acting
+fn is_typing(managers: &super::display::Managers, menu: &super::menu::Menu) -> bool {
+    matches!(
+        managers.state_manager.current_state,
+        StateType::Name | StateType::Directory
+    ) || menu.is_filtering()
+}
+
+// What typing does on screens where it isn't bound to actions
+fn typing_note(state: &StateType) -> Option<&'static str> {
+    match state {
+        StateType::Name => Some("Type your name, Backspace deletes."),
+        StateType::Directory => Some("Type to search, Backspace deletes."),
+        StateType::GameSaveSuccess
+        | StateType::GameSaveError
+        | StateType::GameLoadError
+        | StateType::GameInitError
+        | StateType::Notice
+        | StateType::NameConfirm => None,
+        _ => Some("While filtering, typing narrows the menu and Backspace deletes."),
+    }
+}
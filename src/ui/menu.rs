@@ -1,20 +1,45 @@
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::layout::{Position, Rect};
+use ratatui::text::{Line, Span};
 use ratatui::widgets::ListItem;
+use std::mem::{discriminant, Discriminant};
+use strum::IntoEnumIterator;
 
 // Consts for menu options
-const OPTIONS_MAIN_MENU: [&str; 3] = ["New Game", "Load Game", "Exit"];
-const OPTIONS_CONFIRM: [&str; 2] = ["Enter to Confirm", "Esc to Cancel"];
-const OPTIONS_CONTINUE: [&str; 1] = ["Enter to Continue"];
+const OPTIONS_MAIN_MENU: [&str; 5] = ["New Game", "Load Game", "Keybindings", "Themes", "Exit"];
+const OPTIONS_CONTINUE: [&str; 1] = ["Continue"];
 const OPTIONS_BACK: [&str; 1] = ["Back"];
-const OPTIONS_GAME: [&str; 6] = [
+const OPTIONS_TAKE_ALL: [&str; 1] = ["Take All"];
+const OPTIONS_LOCKPICK: [&str; 2] = ["Pick the Lock", "Back"];
+const OPTIONS_TRADE: [&str; 1] = ["Trade"];
+const OPTIONS_SHOP: [&str; 3] = ["Buy", "Sell", "Back"];
+const OPTIONS_TAVERN_SERVICES: [&str; 1] = ["Tavern Services"];
+const OPTIONS_TAVERN: [&str; 4] = ["Hear Rumours", "Notice Board", "Book a Coach", "Back"];
+const OPTIONS_TEMPLE_SERVICES: [&str; 1] = ["Temple Services"];
+const OPTIONS_TEMPLE: [&str; 2] = ["Set as Return Point", "Back"];
+const OPTIONS_GOODBYE: [&str; 1] = ["Goodbye"];
+const OPTIONS_NPC: [&str; 2] = ["Talk", "Back"];
+const OPTIONS_DIRECTORY_NPC: [&str; 2] = ["Plot Route", "Back"];
+const OPTIONS_GAME: [&str; 12] = [
     "Time",
     "Weather",
     "Travel to Town",
     "Travel to Building",
+    "Map",
+    "Inventory",
+    "Character",
+    "Journal",
+    "Directory",
+    "Records",
     "Save",
     "Quit",
 ];
 const OPTIONS_GAME_QUIT: [&str; 2] = ["Yes", "No"];
+const OPTIONS_TRAVEL_CONFIRM: [&str; 2] = ["Yes", "No"];
+const OPTIONS_INVENTORY_ITEM: [&str; 3] = ["Use", "Drop", "Back"];
+
+// Consts for exploration, places the player hasn't been are marked and towns off the map are hidden
+pub const UNVISITED_MARKER: &str = " (Unvisited)";
+pub const UNDISCOVERED_TOWN: &str = "???";
 
 // Struct for Menu
 pub struct Menu {
@@ -22,6 +47,10 @@ pub struct Menu {
     pub selected_index: usize,
     pub scroll_offset: usize,
     pub visible_count: usize,
+    pub area: Rect,
+    filter: Option<String>,
+    matches: Vec<(usize, Vec<usize>)>,
+    filtered_state: Option<Discriminant<crate::core::states::StateType>>,
 }
 
 // Functions for Menu
@@ -33,28 +62,131 @@ impl Menu {
             selected_index: 0,
             scroll_offset: 0,
             visible_count: 0,
+            area: Rect::default(),
+            filter: None,
+            matches: Vec::new(),
+            filtered_state: None,
         }
     }
 
     // Move cursor down the menu options
     pub fn next(&mut self) {
-        if self.selected_index < self.menu_options.len() - 1 {
-            self.selected_index += 1;
-        }
-
-        if self.selected_index >= self.scroll_offset + self.visible_count {
-            self.scroll_offset += 1;
+        if let Some(position) = self.position() {
+            self.move_to(position + 1);
         }
     }
 
     // Move cursor up the menu options
     pub fn previous(&mut self) {
-        if self.selected_index > 0 {
-            self.selected_index -= 1;
+        if let Some(position) = self.position() {
+            self.move_to(position.saturating_sub(1));
+        }
+    }
+
+    // Move cursor down a page of menu options
+    pub fn page_down(&mut self) {
+        if let Some(position) = self.position() {
+            self.move_to(position + self.visible_count.max(1));
         }
+    }
+
+    // Move cursor up a page of menu options
+    pub fn page_up(&mut self) {
+        if let Some(position) = self.position() {
+            self.move_to(position.saturating_sub(self.visible_count.max(1)));
+        }
+    }
+
+    // Move cursor to the first menu option
+    pub fn first(&mut self) {
+        self.move_to(0);
+    }
 
-        if self.selected_index < self.scroll_offset {
-            self.scroll_offset -= self.scroll_offset.saturating_sub(1);
+    // Move cursor to the last menu option
+    pub fn last(&mut self) {
+        self.move_to(self.matches.len().saturating_sub(1));
+    }
+
+    // Whether the cursor is on an option, it isn't when the filter matches nothing
+    pub fn has_selection(&self) -> bool {
+        self.position().is_some()
+    }
+
+    // Whether the player is typing a filter
+    pub fn is_filtering(&self) -> bool {
+        self.filter.is_some()
+    }
+
+    // Start typing a filter
+    pub fn start_filter(&mut self) {
+        self.filter.get_or_insert_with(String::new);
+    }
+
+    // Add a character to the filter
+    pub fn filter_push(&mut self, c: char) {
+        self.filter.get_or_insert_with(String::new).push(c);
+        self.scroll_offset = 0;
+    }
+
+    // Remove the last character of the filter, stops filtering once it's empty
+    pub fn filter_pop(&mut self) {
+        if let Some(filter) = self.filter.as_mut() {
+            if filter.pop().is_none() {
+                self.filter = None;
+            }
+        }
+    }
+
+    // Stop filtering and show every option again
+    pub fn clear_filter(&mut self) {
+        self.filter = None;
+    }
+
+    // Title of the menu, showing the filter while one is typed
+    pub fn title(&self) -> String {
+        match &self.filter {
+            Some(filter) => format!("Menu (Filter: {}_)", filter),
+            None => "Menu".into(),
+        }
+    }
+
+    // Index of the menu option at a clicked position, if there is one
+    pub fn option_at(&self, column: u16, row: u16) -> Option<usize> {
+        if !self.area.contains(Position::new(column, row)) {
+            return None;
+        }
+
+        // Skip the border above the first option
+        let line = (row - self.area.y).checked_sub(1)? as usize;
+
+        if line >= self.visible_count {
+            return None;
+        }
+
+        self.matches
+            .get(self.scroll_offset + line)
+            .map(|(index, _)| *index)
+    }
+
+    // Position of the cursor among the options that match the filter
+    fn position(&self) -> Option<usize> {
+        self.matches
+            .iter()
+            .position(|(index, _)| *index == self.selected_index)
+    }
+
+    // Move the cursor to a position among the options that match the filter, scrolling to keep it in view
+    fn move_to(&mut self, position: usize) {
+        let position = position.min(self.matches.len().saturating_sub(1));
+
+        if let Some((index, _)) = self.matches.get(position) {
+            self.selected_index = *index;
+        }
+
+        if position < self.scroll_offset {
+            self.scroll_offset = position;
+        } else if self.visible_count > 0 && position >= self.scroll_offset + self.visible_count {
+            self.scroll_offset = position + 1 - self.visible_count;
         }
     }
 
@@ -68,16 +200,14 @@ impl Menu {
                 self.menu_options
                     .extend(OPTIONS_MAIN_MENU.iter().map(|&option| option.to_string()));
             }
-            // New Game
-            crate::core::states::StateType::Name | crate::core::states::StateType::NameConfirm => {
-                self.menu_options
-                    .extend(OPTIONS_CONFIRM.iter().map(|&option| option.to_string()));
-            }
-            // Save Game, Load Game (Error), and Initialize Game (Error)
+            // New Game (the name is typed into the popup, so there's nothing to choose)
+            crate::core::states::StateType::Name | crate::core::states::StateType::NameConfirm => {}
+            // Save Game, Load Game (Error), Initialize Game (Error), and Notice
             crate::core::states::StateType::GameSaveSuccess
             | crate::core::states::StateType::GameSaveError
             | crate::core::states::StateType::GameLoadError
-            | crate::core::states::StateType::GameInitError => {
+            | crate::core::states::StateType::GameInitError
+            | crate::core::states::StateType::Notice => {
                 self.menu_options
                     .extend(OPTIONS_CONTINUE.iter().map(|&option| option.to_string()));
             }
@@ -86,6 +216,14 @@ impl Menu {
                 self.menu_options
                     .extend(OPTIONS_GAME_QUIT.iter().map(|&option| option.to_string()));
             }
+            // Travel Confirmation
+            crate::core::states::StateType::TravelConfirm => {
+                self.menu_options.extend(
+                    OPTIONS_TRAVEL_CONFIRM
+                        .iter()
+                        .map(|&option| option.to_string()),
+                );
+            }
             // Game, Time, and Weather
             crate::core::states::StateType::Game
             | crate::core::states::StateType::Time
@@ -95,9 +233,22 @@ impl Menu {
             }
             // Travel Town
             crate::core::states::StateType::TravelTown => {
-                if let Some(world) = managers.world_manager.world.as_ref() {
-                    self.menu_options
-                        .extend(world.towns.values().map(|town| town.name.clone()));
+                if let (Some(player), Some(world)) = (
+                    managers.world_manager.player.as_ref(),
+                    managers.world_manager.world.as_ref(),
+                ) {
+                    // Sorted so the list is in the same order every time
+                    let mut towns: Vec<&crate::world::manager::Town> =
+                        world.towns.values().collect();
+                    towns.sort_by(|a, b| a.name.cmp(&b.name));
+
+                    self.menu_options.extend(towns.iter().map(|town| {
+                        if player.visited_towns.contains(&town.id) {
+                            town.name.clone()
+                        } else {
+                            format!("{}{}", town.name, UNVISITED_MARKER)
+                        }
+                    }));
                     self.menu_options.push(OPTIONS_BACK[0].to_string());
                 }
             }
@@ -106,9 +257,14 @@ impl Menu {
                 if let Some(player) = managers.world_manager.player.as_ref() {
                     if let Some(world) = managers.world_manager.world.as_ref() {
                         if let Some(town) = world.towns.get(&player.current_town_id).as_ref() {
-                            self.menu_options.extend(
-                                town.buildings.iter().map(|building| building.name.clone()),
-                            );
+                            self.menu_options
+                                .extend(town.buildings.iter().map(|building| {
+                                    if player.visited_buildings.contains(&building.id) {
+                                        building.name.clone()
+                                    } else {
+                                        format!("{}{}", building.name, UNVISITED_MARKER)
+                                    }
+                                }));
                             self.menu_options.push(OPTIONS_BACK[0].to_string());
                         }
                     }
@@ -120,8 +276,33 @@ impl Menu {
                     if let Some(current_building_id) = player.current_building_id.as_ref() {
                         if let Some(world) = managers.world_manager.world.as_ref() {
                             if let Some(building) = world.buildings.get(current_building_id) {
-                                self.menu_options
-                                    .extend(building.rooms.iter().map(|room| room.id.to_string()));
+                                self.menu_options.extend(building.rooms.iter().map(|room| {
+                                    if player.visited_rooms.contains(&room.id) {
+                                        room.id.to_string()
+                                    } else {
+                                        format!("{}{}", room.id, UNVISITED_MARKER)
+                                    }
+                                }));
+
+                                if building.building_type
+                                    == crate::world::manager::BuildingType::Shop
+                                {
+                                    self.menu_options.push(OPTIONS_TRADE[0].to_string());
+                                }
+
+                                if building.building_type
+                                    == crate::world::manager::BuildingType::Tavern
+                                {
+                                    self.menu_options
+                                        .push(OPTIONS_TAVERN_SERVICES[0].to_string());
+                                }
+
+                                if building.building_type
+                                    == crate::world::manager::BuildingType::Temple
+                                {
+                                    self.menu_options
+                                        .push(OPTIONS_TEMPLE_SERVICES[0].to_string());
+                                }
                             }
                         }
                     }
@@ -130,53 +311,504 @@ impl Menu {
             }
             // Room
             crate::core::states::StateType::Room => {
+                if let Some(player) = managers.world_manager.player.as_ref() {
+                    if let Some(current_room_id) = player.current_room_id.as_ref() {
+                        if let Some(world) = managers.world_manager.world.as_ref() {
+                            if let Some(room) = world.rooms.get(current_room_id) {
+                                self.menu_options
+                                    .extend(room.containers.iter().enumerate().map(
+                                        |(i, container)| {
+                                            if managers
+                                                .world_manager
+                                                .is_container_locked(container.id)
+                                            {
+                                                format!(
+                                                    "Open {:?} {} (Locked)",
+                                                    container.container_type,
+                                                    i + 1
+                                                )
+                                            } else if managers
+                                                .world_manager
+                                                .is_container_empty(container.id)
+                                            {
+                                                format!(
+                                                    "Open {:?} {} (Empty)",
+                                                    container.container_type,
+                                                    i + 1
+                                                )
+                                            } else {
+                                                format!(
+                                                    "Open {:?} {}",
+                                                    container.container_type,
+                                                    i + 1
+                                                )
+                                            }
+                                        },
+                                    ));
+                                self.menu_options.extend(
+                                    managers
+                                        .world_manager
+                                        .npcs_in_room(room.id, &managers.time_manager.phase())
+                                        .iter()
+                                        .map(|npc| format!("Approach {}", npc.name)),
+                                );
+                            }
+                        }
+                    }
+                }
+                self.menu_options.push(OPTIONS_BACK[0].to_string());
+            }
+            // Container
+            crate::core::states::StateType::Container => {
+                if let Some(contents) =
+                    managers
+                        .state_manager
+                        .selected_container_id
+                        .and_then(|container_id| {
+                            managers.world_manager.container_contents.get(&container_id)
+                        })
+                {
+                    self.menu_options
+                        .extend(contents.iter().map(|slot| format!("Take {}", slot.label())));
+
+                    if !contents.is_empty() {
+                        self.menu_options.push(OPTIONS_TAKE_ALL[0].to_string());
+                    }
+                }
+                self.menu_options.push(OPTIONS_BACK[0].to_string());
+            }
+            // Lockpick
+            crate::core::states::StateType::Lockpick => {
                 self.menu_options
-                    .extend(OPTIONS_BACK.iter().map(|&option| option.to_string()));
+                    .extend(OPTIONS_LOCKPICK.iter().map(|&option| option.to_string()));
+            }
+            // Shop
+            crate::core::states::StateType::Shop => {
+                let bounty = managers
+                    .world_manager
+                    .player
+                    .as_ref()
+                    .and_then(|player| player.bounties.get(&player.current_town_id).copied())
+                    .unwrap_or(0);
+
+                if bounty > 0 {
+                    self.menu_options
+                        .push(format!("Pay Bounty ({} gold)", bounty));
+                    self.menu_options.push(OPTIONS_BACK[0].to_string());
+                } else {
+                    self.menu_options
+                        .extend(OPTIONS_SHOP.iter().map(|&option| option.to_string()));
+                }
+            }
+            // Shop (Buy)
+            crate::core::states::StateType::ShopBuy => {
+                let standing = managers.world_manager.standing();
+                if let Some(player) = managers.world_manager.player.as_ref() {
+                    if let Some(stock) = player
+                        .current_building_id
+                        .and_then(|building_id| managers.world_manager.shop_stock.get(&building_id))
+                    {
+                        self.menu_options.extend(stock.items.iter().map(|slot| {
+                            format!(
+                                "{} - {} gold ({} left)",
+                                slot.item.name,
+                                crate::world::shop::buy_price(
+                                    &slot.item,
+                                    player.current_town_id,
+                                    standing,
+                                ),
+                                slot.quantity
+                            )
+                        }));
+                    }
+                }
+                self.menu_options.push(OPTIONS_BACK[0].to_string());
+            }
+            // Shop (Sell)
+            crate::core::states::StateType::ShopSell => {
+                let standing = managers.world_manager.standing();
+                if let Some(player) = managers.world_manager.player.as_ref() {
+                    self.menu_options
+                        .extend(player.inventory.slots.iter().map(|slot| {
+                            format!(
+                                "{} - {} gold each",
+                                slot.label(),
+                                crate::world::shop::sell_price(
+                                    &slot.item,
+                                    player.current_town_id,
+                                    standing,
+                                )
+                            )
+                        }));
+                }
+                self.menu_options.push(OPTIONS_BACK[0].to_string());
+            }
+            // Tavern
+            crate::core::states::StateType::Tavern => {
+                let standing = managers.world_manager.standing();
+                if let Some(player) = managers.world_manager.player.as_ref() {
+                    let food_price =
+                        crate::entities::item::Item::from_id(crate::entities::item::ITEM_BREAD)
+                            .map(|item| {
+                                crate::world::shop::buy_price(
+                                    &item,
+                                    player.current_town_id,
+                                    standing,
+                                )
+                            })
+                            .unwrap_or(0);
+                    let drink_price =
+                        crate::entities::item::Item::from_id(crate::entities::item::ITEM_ALE)
+                            .map(|item| {
+                                crate::world::shop::buy_price(
+                                    &item,
+                                    player.current_town_id,
+                                    standing,
+                                )
+                            })
+                            .unwrap_or(0);
+
+                    self.menu_options.push(format!(
+                        "Rest until Dawn ({} gold)",
+                        crate::world::tavern::rest_price(player.current_town_id)
+                    ));
+                    self.menu_options.push(format!(
+                        "Hot Meal ({} gold)",
+                        crate::world::tavern::meal_price(player.current_town_id)
+                    ));
+                    self.menu_options
+                        .push(format!("Buy Bread ({} gold)", food_price));
+                    self.menu_options
+                        .push(format!("Buy Ale ({} gold)", drink_price));
+                }
+                self.menu_options
+                    .extend(OPTIONS_TAVERN.iter().map(|&option| option.to_string()));
+            }
+            // Tavern (Coach)
+            crate::core::states::StateType::TavernCoach => {
+                if let Some(player) = managers.world_manager.player.as_ref() {
+                    self.menu_options.extend(
+                        crate::world::tavern::coach_destinations(
+                            &managers.world_manager,
+                            player.current_town_id,
+                        )
+                        .iter()
+                        .map(|(town_name, fare)| {
+                            let visited =
+                                managers.world_manager.world.as_ref().is_some_and(|world| {
+                                    world.towns.values().any(|town| {
+                                        town.name == *town_name
+                                            && player.visited_towns.contains(&town.id)
+                                    })
+                                });
+
+                            if visited {
+                                format!("{} - {} gold", town_name, fare)
+                            } else {
+                                format!("{} - {} gold{}", town_name, fare, UNVISITED_MARKER)
+                            }
+                        }),
+                    );
+                }
+                self.menu_options.push(OPTIONS_BACK[0].to_string());
+            }
+            // Tavern (Notice Board)
+            crate::core::states::StateType::TavernBoard => {
+                if let Some(board) = managers
+                    .world_manager
+                    .player
+                    .as_ref()
+                    .and_then(|player| player.current_building_id)
+                    .and_then(|building_id| managers.world_manager.quest_boards.get(&building_id))
+                {
+                    self.menu_options.extend(
+                        board
+                            .quests
+                            .iter()
+                            .map(|log| format!("{} - {} gold", log.title, log.reward.gold)),
+                    );
+                }
+                self.menu_options.push(OPTIONS_BACK[0].to_string());
+            }
+            // Directory
+            crate::core::states::StateType::Directory => {
+                if let Some(world) = managers.world_manager.world.as_ref() {
+                    self.menu_options.extend(
+                        managers
+                            .world_manager
+                            .search_npcs(&managers.state_manager.search_query)
+                            .iter()
+                            .map(|npc| {
+                                let town_name = world
+                                    .towns
+                                    .get(&npc.town_id)
+                                    .map(|town| town.name.clone())
+                                    .unwrap_or_default();
+
+                                format!("{} ({:?}) - {}", npc.name, npc.race, town_name)
+                            }),
+                    );
+                }
+                self.menu_options.push(OPTIONS_BACK[0].to_string());
+            }
+            // Directory (NPC)
+            crate::core::states::StateType::DirectoryNpc => {
+                self.menu_options.extend(
+                    OPTIONS_DIRECTORY_NPC
+                        .iter()
+                        .map(|&option| option.to_string()),
+                );
+            }
+            // NPC
+            crate::core::states::StateType::Npc => {
+                self.menu_options
+                    .extend(OPTIONS_NPC.iter().map(|&option| option.to_string()));
+            }
+            // Dialogue
+            crate::core::states::StateType::Dialogue => {
+                if let Some(context) = managers.state_manager.selected_npc_id.and_then(|npc_id| {
+                    crate::world::dialogue::DialogueContext::new(
+                        &managers.world_manager,
+                        &managers.time_manager,
+                        &managers.weather_manager,
+                        npc_id,
+                    )
+                }) {
+                    self.menu_options
+                        .extend(managers.dialogue_manager.responses(&context));
+                }
+
+                if self.menu_options.is_empty() {
+                    self.menu_options.push(OPTIONS_GOODBYE[0].to_string());
+                }
+            }
+            // Temple
+            crate::core::states::StateType::Temple => {
+                if let Some(player) = managers.world_manager.player.as_ref() {
+                    self.menu_options.push(format!(
+                        "Heal Wounds ({} gold)",
+                        crate::world::temple::heal_price(
+                            player.condition.max_health - player.condition.health,
+                            player.current_town_id
+                        )
+                    ));
+                    self.menu_options.push(format!(
+                        "Cure Ailments ({} gold)",
+                        crate::world::temple::cure_price(
+                            player.condition.ailments.len(),
+                            player.current_town_id
+                        )
+                    ));
+                    self.menu_options.push("Receive a Blessing".into());
+                    self.menu_options
+                        .push(format!("Donate {} Gold", crate::world::temple::DONATION));
+                }
+                self.menu_options
+                    .extend(OPTIONS_TEMPLE.iter().map(|&option| option.to_string()));
+            }
+            // Temple (Blessing)
+            crate::core::states::StateType::TempleBlessing => {
+                self.menu_options
+                    .extend(crate::world::temple::BLESSINGS.iter().map(
+                        |(blessing_type, price, days)| {
+                            format!("{} - {} gold ({} days)", blessing_type.name(), price, days)
+                        },
+                    ));
+                self.menu_options.push(OPTIONS_BACK[0].to_string());
+            }
+            // Inventory
+            crate::core::states::StateType::Inventory => {
+                if let Some(player) = managers.world_manager.player.as_ref() {
+                    self.menu_options
+                        .extend(player.inventory.slots.iter().map(|slot| slot.label()));
+                }
+                self.menu_options.push(OPTIONS_BACK[0].to_string());
+            }
+            // Inventory Item
+            crate::core::states::StateType::InventoryItem => {
+                self.menu_options.extend(
+                    OPTIONS_INVENTORY_ITEM
+                        .iter()
+                        .map(|&option| option.to_string()),
+                );
+            }
+            // Journal
+            crate::core::states::StateType::Journal => {
+                if let Some(player) = managers.world_manager.player.as_ref() {
+                    self.menu_options.extend(player.journal.iter().map(|log| {
+                        format!(
+                            "{} ({})",
+                            log.title,
+                            if log.is_complete() {
+                                "Completed"
+                            } else {
+                                "Active"
+                            }
+                        )
+                    }));
+                }
+                self.menu_options.push(OPTIONS_BACK[0].to_string());
+            }
+            // Journal (Quest)
+            crate::core::states::StateType::JournalQuest => {
+                self.menu_options.push(OPTIONS_BACK[0].to_string());
+            }
+            // Map and Records
+            crate::core::states::StateType::Map | crate::core::states::StateType::Records => {
+                self.menu_options.push(OPTIONS_BACK[0].to_string());
+            }
+            // Keybindings
+            crate::core::states::StateType::Keybindings => {
+                self.menu_options.extend(
+                    crate::core::keymap::Preset::iter()
+                        .map(|preset| format!("{} Preset", preset.name())),
+                );
+                self.menu_options.push(OPTIONS_BACK[0].to_string());
+            }
+            // Themes
+            crate::core::states::StateType::Themes => {
+                self.menu_options.extend(
+                    crate::ui::theme::Palette::iter()
+                        .map(|palette| format!("{} Theme", palette.name())),
+                );
+                self.menu_options.push(OPTIONS_BACK[0].to_string());
+            }
+            // Character
+            crate::core::states::StateType::Character => {
+                if let Some(player) = managers.world_manager.player.as_ref() {
+                    self.menu_options.extend(
+                        crate::entities::attributes::Attribute::ALL
+                            .iter()
+                            .map(|attribute| format!("Raise {}", attribute.name())),
+                    );
+                    self.menu_options
+                        .extend(
+                            crate::entities::progression::Skill::ALL
+                                .iter()
+                                .map(|skill| {
+                                    let rank = player.progression.rank(skill);
+
+                                    if rank >= crate::entities::progression::MAX_SKILL_RANK {
+                                        format!("{} (Mastered)", skill.name())
+                                    } else {
+                                        format!("Learn {} (Rank {})", skill.name(), rank + 1)
+                                    }
+                                }),
+                        );
+                }
+                self.menu_options.push(OPTIONS_BACK[0].to_string());
             }
         };
-    }
 
-    // Renders the Menu based on current state
-    pub fn render(&self, managers: &super::display::Managers) -> Vec<ListItem> {
-        match managers.state_manager.current_state {
-            // New Game, Save Game, Load Game (Error), and Initialize Game (Error)
-            crate::core::states::StateType::Name
-            | crate::core::states::StateType::NameConfirm
-            | crate::core::states::StateType::GameSaveSuccess
-            | crate::core::states::StateType::GameSaveError
-            | crate::core::states::StateType::GameLoadError
-            | crate::core::states::StateType::GameInitError => {
-                let list: Vec<ListItem> = self
-                    .menu_options
-                    .iter()
-                    .map(|option| ListItem::new(option.clone()).style(Style::default()))
-                    .collect();
+        // Keep the cursor inside the menu if the options have shrunk
+        if self.selected_index >= self.menu_options.len() {
+            self.selected_index = self.menu_options.len().saturating_sub(1);
+        }
+
+        // The filter only lasts as long as the menu it was typed into
+        let state = discriminant(&managers.state_manager.current_state);
+        if self.filtered_state != Some(state) {
+            self.filter = None;
+            self.filtered_state = Some(state);
+        }
+
+        self.matches = self
+            .menu_options
+            .iter()
+            .enumerate()
+            .filter_map(|(index, option)| match &self.filter {
+                Some(filter) => fuzzy_match(option, filter).map(|matched| (index, matched)),
+                None => Some((index, Vec::new())),
+            })
+            .collect();
 
-                list
+        // Keep the cursor on an option that matches the filter
+        match self.position() {
+            Some(position) => self.move_to(position),
+            None => {
+                self.scroll_offset = 0;
+                self.move_to(0);
             }
-            // All other states
-            _ => {
-                let end = (self.scroll_offset + self.visible_count).min(self.menu_options.len());
-                let visible_items = &self.menu_options[self.scroll_offset..end];
+        }
+    }
+
+    // Renders the Menu options in view
+    pub fn render(&self, managers: &super::display::Managers) -> Vec<ListItem<'_>> {
+        let theme = &managers.config_manager.theme;
+
+        self.matches
+            .iter()
+            .skip(self.scroll_offset)
+            .take(self.visible_count)
+            .map(|(index, matched)| {
+                let style = if *index == self.selected_index {
+                    theme.menu_selected
+                } else {
+                    theme.menu
+                };
 
-                let list: Vec<ListItem> = visible_items
-                    .iter()
+                // Characters matching the filter are picked out
+                let spans: Vec<Span> = self.menu_options[*index]
+                    .chars()
                     .enumerate()
-                    .map(|(i, option)| {
-                        let absolute_index = self.scroll_offset + i;
-                        let style = if absolute_index == self.selected_index {
-                            Style::default()
-                                .fg(Color::Yellow)
-                                .add_modifier(Modifier::BOLD)
+                    .map(|(position, c)| {
+                        if matched.contains(&position) {
+                            Span::styled(c.to_string(), style.patch(theme.menu_match))
                         } else {
-                            Style::default().fg(Color::Green)
-                        };
-                        ListItem::new(option.clone()).style(style)
+                            Span::styled(c.to_string(), style)
+                        }
                     })
                     .collect();
 
-                list
+                ListItem::new(Line::from(spans))
+            })
+            .collect()
+    }
+}
+
+// Match a filter against a menu option, the filter's characters must appear in order but not
+// necessarily together, returns the positions of the matched characters
+fn fuzzy_match(option: &str, filter: &str) -> Option<Vec<usize>> {
+    let mut filter_chars = filter.chars().flat_map(char::to_lowercase).peekable();
+    let mut matched = Vec::new();
+
+    for (position, c) in option.chars().enumerate() {
+        match filter_chars.peek() {
+            Some(&wanted) if c.to_lowercase().eq(std::iter::once(wanted)) => {
+                matched.push(position);
+                filter_chars.next();
             }
+            Some(_) => {}
+            None => break,
         }
     }
+
+    filter_chars.peek().is_none().then_some(matched)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzzy_match_finds_characters_in_order() {
+        assert_eq!(fuzzy_match("Travel to Town", "tvt"), Some(vec![0, 3, 7]));
+        assert_eq!(fuzzy_match("Save", "sve"), Some(vec![0, 2, 3]));
+    }
+
+    #[test]
+    fn fuzzy_match_ignores_case() {
+        assert_eq!(fuzzy_match("Inventory", "INV"), Some(vec![0, 1, 2]));
+    }
+
+    #[test]
+    fn fuzzy_match_rejects_out_of_order_or_missing_characters() {
+        assert_eq!(fuzzy_match("Map", "pam"), None);
+        assert_eq!(fuzzy_match("Map", "maps"), None);
+    }
+
+    #[test]
+    fn empty_filter_matches_everything() {
+        assert_eq!(fuzzy_match("Quit", ""), Some(Vec::new()));
+    }
 }
@@ -0,0 +1,133 @@
+use ratatui::layout::{Position, Rect};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Paragraph, Wrap};
+
+// Struct for the Message Log pane
+pub struct Messages {
+    scroll: usize,
+    pub area: Rect,
+}
+
+// Functions for the Message Log pane
+impl Messages {
+    // Create a new Message Log pane, following the newest messages
+    pub fn new() -> Self {
+        Self {
+            scroll: 0,
+            area: Rect::default(),
+        }
+    }
+
+    // Scroll back to older messages, stopping once the oldest ones fill the pane
+    pub fn scroll_up(&mut self, managers: &super::display::Managers) {
+        self.scroll = (self.scroll + 1).min(self.oldest_scroll(&managers.message_manager.messages));
+    }
+
+    // Scroll forward to newer messages
+    pub fn scroll_down(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1);
+    }
+
+    // Whether a position is over the pane
+    pub fn contains(&self, column: u16, row: u16) -> bool {
+        self.area.contains(Position::new(column, row))
+    }
+
+    // Title of the pane, showing how far back it's scrolled
+    pub fn title(&self) -> String {
+        if self.scroll > 0 {
+            format!("Messages ({} newer)", self.scroll)
+        } else {
+            "Messages".into()
+        }
+    }
+
+    // Renders the messages that fit in the pane, newest at the bottom
+    pub fn render(&self, managers: &super::display::Managers) -> Vec<Line<'_>> {
+        let theme = &managers.config_manager.theme;
+        let messages = &managers.message_manager.messages;
+
+        // Long messages wrap on words, so count the rows each one takes working back from the newest
+        let end = messages.len().saturating_sub(self.scroll);
+        let mut lines: Vec<Line> = Vec::new();
+        let mut rows = 0;
+        for message in messages[..end].iter().rev() {
+            let line = Line::from(vec![
+                Span::styled(format!("[{}] ", message.timestamp), theme.accent),
+                Span::raw(message.text.clone()),
+            ]);
+
+            rows += self.rows(&line);
+            if rows > self.visible_count() {
+                break;
+            }
+
+            lines.insert(0, line);
+        }
+
+        lines
+    }
+
+    // Furthest back the log can scroll, where the oldest messages that fit fill the pane
+    fn oldest_scroll(&self, messages: &[crate::core::messages::Message]) -> usize {
+        let mut rows = 0;
+        let fitting = messages
+            .iter()
+            .take_while(|message| {
+                rows += self.rows(&Line::from(format!(
+                    "[{}] {}",
+                    message.timestamp, message.text
+                )));
+                rows <= self.visible_count()
+            })
+            .count();
+
+        messages.len().saturating_sub(fitting.max(1))
+    }
+
+    // Number of rows a message takes once wrapped to fit inside the pane's border
+    fn rows(&self, line: &Line) -> usize {
+        Paragraph::new(line.clone())
+            .wrap(Wrap { trim: false })
+            .line_count(self.area.width.saturating_sub(2))
+    }
+
+    // Number of rows that fit inside the pane's border
+    fn visible_count(&self) -> usize {
+        self.area.height.saturating_sub(2) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::messages::Message;
+
+    fn message(text: &str) -> Message {
+        Message {
+            timestamp: "Day 1, 06:00".into(),
+            text: text.into(),
+        }
+    }
+
+    #[test]
+    fn scrolling_back_stops_when_the_oldest_messages_fill_the_pane() {
+        // Three rows of twenty columns inside the border
+        let pane = Messages {
+            scroll: 0,
+            area: Rect::new(0, 0, 22, 5),
+        };
+
+        // The first message wraps onto a second row, so only two of the oldest fit
+        let messages = [
+            message("Bob sets out from Brook."),
+            message("Hi."),
+            message("Hi."),
+            message("Hi."),
+        ];
+        assert_eq!(pane.oldest_scroll(&messages), 2);
+
+        // Everything fits, so there's nothing to scroll back to
+        assert_eq!(pane.oldest_scroll(&messages[1..]), 0);
+    }
+}
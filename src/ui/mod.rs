@@ -1,5 +1,8 @@
 pub mod display;
+pub mod help;
 pub mod menu;
+pub mod messages;
 pub mod popup;
 pub mod stats;
+pub mod theme;
 pub mod viewport;
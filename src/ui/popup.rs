@@ -1,11 +1,11 @@
-use ratatui::style::Stylize;
-use ratatui::text::Line;
+use ratatui::text::{Line, Span};
 
 // Struct for Pop Up
 pub struct Popup {
     pub display: bool,
     title: String,
     pub input: String,
+    pub message: String,
 }
 
 // Functions for Pop Up
@@ -16,6 +16,7 @@ impl Popup {
             display: false,
             title: String::new(),
             input: String::new(),
+            message: String::new(),
         }
     }
 
@@ -44,6 +45,16 @@ impl Popup {
                 self.display = true;
                 self.title = "Quit Game".into();
             }
+            // Travel Confirmation
+            crate::core::states::StateType::TravelConfirm => {
+                self.display = true;
+                self.title = "Travel".into();
+            }
+            // Notice
+            crate::core::states::StateType::Notice => {
+                self.display = true;
+                self.title = "Notice".into();
+            }
             // All other states
             _ => {
                 self.display = false;
@@ -53,7 +64,9 @@ impl Popup {
     }
 
     // Renders the Pop Up based on current state
-    pub fn render(&self, managers: &super::display::Managers) -> (String, Vec<Line>) {
+    pub fn render(&self, managers: &super::display::Managers) -> (String, Vec<Line<'_>>) {
+        let theme = &managers.config_manager.theme;
+
         match managers.state_manager.current_state {
             // New Game (Enter Name)
             crate::core::states::StateType::Name => {
@@ -64,7 +77,7 @@ impl Popup {
                 let text = vec![
                     Line::from("\n"),
                     Line::from("Name thyself..."),
-                    Line::from(prompt.yellow()),
+                    Line::from(Span::styled(prompt, theme.accent)),
                 ];
 
                 (title.to_string(), text)
@@ -78,7 +91,7 @@ impl Popup {
                 let text = vec![
                     Line::from("\n"),
                     Line::from("Confirm name..."),
-                    Line::from(name.yellow()),
+                    Line::from(Span::styled(name, theme.accent)),
                 ];
 
                 (title.to_string(), text)
@@ -95,7 +108,10 @@ impl Popup {
             crate::core::states::StateType::GameSaveError => {
                 let title = &self.title;
 
-                let text = vec![Line::from("\n"), Line::from("Error saving game!".red())];
+                let text = vec![
+                    Line::from("\n"),
+                    Line::from(Span::styled("Error saving game!", theme.error)),
+                ];
 
                 (title.to_string(), text)
             }
@@ -103,7 +119,10 @@ impl Popup {
             crate::core::states::StateType::GameLoadError => {
                 let title = &self.title;
 
-                let text = vec![Line::from("\n"), Line::from("Error loading game!".red())];
+                let text = vec![
+                    Line::from("\n"),
+                    Line::from(Span::styled("Error loading game!", theme.error)),
+                ];
 
                 (title.to_string(), text)
             }
@@ -113,7 +132,7 @@ impl Popup {
 
                 let text = vec![
                     Line::from("\n"),
-                    Line::from("Error initializing game!".red()),
+                    Line::from(Span::styled("Error initializing game!", theme.error)),
                 ];
 
                 (title.to_string(), text)
@@ -129,6 +148,41 @@ impl Popup {
 
                 (title.to_string(), text)
             }
+            // Travel Confirmation
+            crate::core::states::StateType::TravelConfirm => {
+                let title = &self.title;
+
+                let mut text = vec![Line::from("\n")];
+                if let (Some(player), Some(world), Some(destination)) = (
+                    managers.world_manager.player.as_ref(),
+                    managers.world_manager.world.as_ref(),
+                    managers.state_manager.selected_town.as_ref(),
+                ) {
+                    if let Some(town) = world.towns.get(&player.current_town_id) {
+                        let miles = managers
+                            .world_manager
+                            .get_travel_distance(&town.name, destination);
+
+                        text.push(Line::from(format!("Walk to {}?", destination)));
+                        text.push(Line::from(format!("It's {} miles.", miles)));
+                    }
+                }
+
+                (title.to_string(), text)
+            }
+            // Notice
+            crate::core::states::StateType::Notice => {
+                let title = &self.title;
+
+                let mut text = vec![Line::from("\n")];
+                text.extend(
+                    self.message
+                        .lines()
+                        .map(|line| Line::from(line.to_string())),
+                );
+
+                (title.to_string(), text)
+            }
             // All other states
             _ => {
                 let title = &self.title;
@@ -1,12 +1,22 @@
-use ratatui::text::Line;
+use ratatui::text::{Line, Span};
 
 // Struct for Stats
 pub struct Stats {
     player_id: String,
     player_name: String,
+    gold: String,
+    level: String,
+    health: String,
+    attributes: String,
+    hunger: String,
+    fatigue: String,
     town_name: String,
     location: String,
     room_id: String,
+    reputation: String,
+    exploration: String,
+    bounty: String,
+    seed: String,
 }
 
 // Functions for Stats
@@ -16,17 +26,28 @@ impl Stats {
         Self {
             player_id: String::new(),
             player_name: String::new(),
+            gold: String::new(),
+            level: String::new(),
+            health: String::new(),
+            attributes: String::new(),
+            hunger: String::new(),
+            fatigue: String::new(),
             town_name: String::new(),
             location: String::new(),
             room_id: String::new(),
+            reputation: String::new(),
+            exploration: String::new(),
+            bounty: String::new(),
+            seed: String::new(),
         }
     }
 
     // Updates any dynamic parts of Stats
     pub fn update(&mut self, managers: &super::display::Managers) {
         match managers.state_manager.current_state {
-            // Game, Save Game, Quit Game, Time, Weather, Travel Town, Travel Building, Building,
-            // and Room
+            // Game, Save Game, Quit Game, Time, Weather, Travel Town, Travel Confirmation,
+            // Travel Building, Building, Room, Container, Lockpick, Shop, Tavern, Temple, NPC,
+            // Dialogue, Directory, Journal, Character, Map, Records, Inventory, and Notice
             crate::core::states::StateType::Game
             | crate::core::states::StateType::GameSaveSuccess
             | crate::core::states::StateType::GameSaveError
@@ -34,16 +55,51 @@ impl Stats {
             | crate::core::states::StateType::Time
             | crate::core::states::StateType::Weather
             | crate::core::states::StateType::TravelTown
+            | crate::core::states::StateType::TravelConfirm
             | crate::core::states::StateType::TravelBuilding
             | crate::core::states::StateType::Building
-            | crate::core::states::StateType::Room => {
+            | crate::core::states::StateType::Room
+            | crate::core::states::StateType::Container
+            | crate::core::states::StateType::Lockpick
+            | crate::core::states::StateType::Shop
+            | crate::core::states::StateType::ShopBuy
+            | crate::core::states::StateType::ShopSell
+            | crate::core::states::StateType::Tavern
+            | crate::core::states::StateType::TavernCoach
+            | crate::core::states::StateType::TavernBoard
+            | crate::core::states::StateType::Temple
+            | crate::core::states::StateType::TempleBlessing
+            | crate::core::states::StateType::Npc
+            | crate::core::states::StateType::Dialogue
+            | crate::core::states::StateType::Directory
+            | crate::core::states::StateType::DirectoryNpc
+            | crate::core::states::StateType::Journal
+            | crate::core::states::StateType::JournalQuest
+            | crate::core::states::StateType::Character
+            | crate::core::states::StateType::Map
+            | crate::core::states::StateType::Records
+            | crate::core::states::StateType::Inventory
+            | crate::core::states::StateType::InventoryItem
+            | crate::core::states::StateType::Notice => {
                 //Get player ID and name
                 if let Some(player) = managers.world_manager.player.as_ref() {
                     self.player_id = format!("Player ID: {}", player.id);
                     self.player_name = format!("Player Name: {}", player.name);
+                    self.gold = format!("Gold: {}", player.gold);
+                    self.level = format!("Level: {}", player.progression.describe());
+                    self.health = format!("Health: {}", player.condition.describe());
+                    self.attributes = format!("Attributes: {}", player.attributes.describe());
+                    self.hunger = format!("Hunger: {}", player.condition.describe_hunger());
+                    self.fatigue = format!("Fatigue: {}", player.condition.describe_fatigue());
                 } else {
                     self.player_id = "Failed to initialize player ID.".into();
                     self.player_name = "Failed to initialize player name".into();
+                    self.gold = "Failed to initialize gold.".into();
+                    self.level = "Failed to initialize level.".into();
+                    self.health = "Failed to initialize health.".into();
+                    self.attributes = "Failed to initialize attributes.".into();
+                    self.hunger = "Failed to initialize hunger.".into();
+                    self.fatigue = "Failed to initialize fatigue.".into();
                 }
 
                 // Get town name
@@ -102,6 +158,49 @@ impl Stats {
                 } else {
                     "Error getting room info!".into()
                 };
+
+                // Get reputation in the current town
+                self.reputation = match managers.world_manager.player.as_ref() {
+                    Some(player) => {
+                        let reputation = player.reputation(player.current_town_id);
+
+                        format!(
+                            "Reputation: {} ({})",
+                            crate::entities::player::standing_label(reputation),
+                            reputation
+                        )
+                    }
+                    None => "Error getting reputation info!".into(),
+                };
+
+                // Get how much of the current town and the world has been explored
+                self.exploration = match managers.world_manager.player.as_ref() {
+                    Some(player) => format!(
+                        "Explored: Town {}%, World {}%",
+                        managers
+                            .world_manager
+                            .town_exploration(player.current_town_id),
+                        managers.world_manager.world_exploration()
+                    ),
+                    None => "Error getting exploration info!".into(),
+                };
+
+                // Get bounty in the current town
+                self.bounty = match managers
+                    .world_manager
+                    .player
+                    .as_ref()
+                    .and_then(|player| player.bounties.get(&player.current_town_id).copied())
+                {
+                    Some(bounty) if bounty > 0 => format!("Wanted! Bounty: {} gold", bounty),
+                    _ => String::new(),
+                };
+
+                // Get seed
+                self.seed = match managers.rng_manager.seed() {
+                    Some(seed) => format!("Seed: {}", seed),
+                    None => "Seed: Unknown".into(),
+                };
             }
             // All other states
             _ => {}
@@ -109,10 +208,11 @@ impl Stats {
     }
 
     // Renders the Stats based on current state
-    pub fn render(&self, managers: &super::display::Managers) -> Vec<Line> {
+    pub fn render(&self, managers: &super::display::Managers) -> Vec<Line<'_>> {
         match managers.state_manager.current_state {
-            // Game, Save Game, Quit Game, Time, Weather, Travel Town, Travel Building, Building,
-            // and Room
+            // Game, Save Game, Quit Game, Time, Weather, Travel Town, Travel Confirmation,
+            // Travel Building, Building, Room, Container, Lockpick, Shop, Tavern, Temple, NPC,
+            // Dialogue, Directory, Journal, Character, Map, Records, Inventory, and Notice
             crate::core::states::StateType::Game
             | crate::core::states::StateType::GameSaveSuccess
             | crate::core::states::StateType::GameSaveError
@@ -120,15 +220,53 @@ impl Stats {
             | crate::core::states::StateType::Time
             | crate::core::states::StateType::Weather
             | crate::core::states::StateType::TravelTown
+            | crate::core::states::StateType::TravelConfirm
             | crate::core::states::StateType::TravelBuilding
             | crate::core::states::StateType::Building
-            | crate::core::states::StateType::Room => {
+            | crate::core::states::StateType::Room
+            | crate::core::states::StateType::Container
+            | crate::core::states::StateType::Lockpick
+            | crate::core::states::StateType::Shop
+            | crate::core::states::StateType::ShopBuy
+            | crate::core::states::StateType::ShopSell
+            | crate::core::states::StateType::Tavern
+            | crate::core::states::StateType::TavernCoach
+            | crate::core::states::StateType::TavernBoard
+            | crate::core::states::StateType::Temple
+            | crate::core::states::StateType::TempleBlessing
+            | crate::core::states::StateType::Npc
+            | crate::core::states::StateType::Dialogue
+            | crate::core::states::StateType::Directory
+            | crate::core::states::StateType::DirectoryNpc
+            | crate::core::states::StateType::Journal
+            | crate::core::states::StateType::JournalQuest
+            | crate::core::states::StateType::Character
+            | crate::core::states::StateType::Map
+            | crate::core::states::StateType::Records
+            | crate::core::states::StateType::Inventory
+            | crate::core::states::StateType::InventoryItem
+            | crate::core::states::StateType::Notice => {
                 vec![
                     Line::from(self.player_id.clone()),
                     Line::from(self.player_name.clone()),
+                    Line::from(self.gold.clone()),
+                    Line::from(self.level.clone()),
+                    Line::from(self.health.clone()),
+                    Line::from(self.attributes.clone()),
+                    Line::from(self.hunger.clone()),
+                    Line::from(self.fatigue.clone()),
                     Line::from(self.town_name.clone()),
                     Line::from(self.location.clone()),
                     Line::from(self.room_id.clone()),
+                    Line::from(self.reputation.clone()),
+                    Line::from(self.exploration.clone()),
+                    Line::from(""),
+                    Line::from(Span::styled(
+                        self.bounty.clone(),
+                        managers.config_manager.theme.error,
+                    )),
+                    Line::from(""),
+                    Line::from(self.seed.clone()),
                 ]
             }
             // All other states
@@ -0,0 +1,174 @@
+use ratatui::style::{Color, Modifier, Style};
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+// Struct for a theme, the styles every widget is drawn with
+pub struct Theme {
+    pub base: Style,
+    pub border: Style,
+    pub text: Style,
+    pub menu: Style,
+    pub menu_selected: Style,
+    pub menu_match: Style,
+    pub accent: Style,
+    pub highlight: Style,
+    pub error: Style,
+}
+
+// Functions for themes
+impl Theme {
+    // Build a theme from a palette, NO_COLOR always wins over the config file
+    pub fn new(palette: &Palette) -> Self {
+        Self::with_no_color(palette, no_color())
+    }
+
+    // Build a theme from a palette, or monochrome when colour is turned off
+    fn with_no_color(palette: &Palette, no_color: bool) -> Self {
+        if no_color {
+            return Self::palette(&Palette::Monochrome);
+        }
+
+        Self::palette(palette)
+    }
+
+    // Styles for each palette
+    fn palette(palette: &Palette) -> Self {
+        match palette {
+            Palette::Default => Self {
+                base: Style::default(),
+                border: Style::default(),
+                text: Style::default(),
+                menu: Style::default().fg(Color::Green),
+                menu_selected: Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+                menu_match: Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::UNDERLINED),
+                accent: Style::default().fg(Color::Yellow),
+                highlight: Style::default()
+                    .fg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+                error: Style::default().fg(Color::Red),
+            },
+            Palette::Dark => Self {
+                base: Style::default().fg(Color::Gray).bg(Color::Black),
+                border: Style::default().fg(Color::DarkGray),
+                text: Style::default().fg(Color::Gray),
+                menu: Style::default().fg(Color::LightGreen),
+                menu_selected: Style::default()
+                    .fg(Color::LightYellow)
+                    .add_modifier(Modifier::BOLD),
+                menu_match: Style::default()
+                    .fg(Color::LightCyan)
+                    .add_modifier(Modifier::UNDERLINED),
+                accent: Style::default().fg(Color::LightYellow),
+                highlight: Style::default()
+                    .fg(Color::LightGreen)
+                    .add_modifier(Modifier::BOLD),
+                error: Style::default().fg(Color::LightRed),
+            },
+            Palette::Solarized => Self {
+                base: Style::default()
+                    .fg(Color::Rgb(131, 148, 150))
+                    .bg(Color::Rgb(0, 43, 54)),
+                border: Style::default().fg(Color::Rgb(88, 110, 117)),
+                text: Style::default().fg(Color::Rgb(131, 148, 150)),
+                menu: Style::default().fg(Color::Rgb(133, 153, 0)),
+                menu_selected: Style::default()
+                    .fg(Color::Rgb(181, 137, 0))
+                    .add_modifier(Modifier::BOLD),
+                menu_match: Style::default()
+                    .fg(Color::Rgb(42, 161, 152))
+                    .add_modifier(Modifier::UNDERLINED),
+                accent: Style::default().fg(Color::Rgb(181, 137, 0)),
+                highlight: Style::default()
+                    .fg(Color::Rgb(38, 139, 210))
+                    .add_modifier(Modifier::BOLD),
+                error: Style::default().fg(Color::Rgb(220, 50, 47)),
+            },
+            Palette::HighContrast => Self {
+                base: Style::default().fg(Color::White).bg(Color::Black),
+                border: Style::default().fg(Color::White),
+                text: Style::default().fg(Color::White),
+                menu: Style::default().fg(Color::White),
+                menu_selected: Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::LightYellow)
+                    .add_modifier(Modifier::BOLD),
+                menu_match: Style::default()
+                    .fg(Color::LightCyan)
+                    .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+                accent: Style::default()
+                    .fg(Color::LightYellow)
+                    .add_modifier(Modifier::BOLD),
+                highlight: Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+                error: Style::default()
+                    .fg(Color::LightRed)
+                    .add_modifier(Modifier::BOLD),
+            },
+            // Only modifiers, so there's something to tell the selection and errors apart by
+            Palette::Monochrome => Self {
+                base: Style::default(),
+                border: Style::default(),
+                text: Style::default(),
+                menu: Style::default(),
+                menu_selected: Style::default().add_modifier(Modifier::REVERSED | Modifier::BOLD),
+                menu_match: Style::default().add_modifier(Modifier::UNDERLINED),
+                accent: Style::default().add_modifier(Modifier::BOLD),
+                highlight: Style::default().add_modifier(Modifier::BOLD),
+                error: Style::default().add_modifier(Modifier::BOLD),
+            },
+        }
+    }
+}
+
+// Enum for the named palettes a theme can be built from
+#[derive(Debug, Clone, Default, PartialEq, EnumIter, Serialize, Deserialize)]
+pub enum Palette {
+    #[default]
+    Default,
+    Dark,
+    Solarized,
+    HighContrast,
+    Monochrome,
+}
+
+// Functions for palettes
+impl Palette {
+    // Name shown to the player
+    pub fn name(&self) -> &str {
+        match self {
+            Palette::Default => "Default",
+            Palette::Dark => "Dark",
+            Palette::Solarized => "Solarized",
+            Palette::HighContrast => "High Contrast",
+            Palette::Monochrome => "Monochrome",
+        }
+    }
+}
+
+// Whether colour has been turned off with NO_COLOR, see https://no-color.org
+pub fn no_color() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_color_falls_back_to_monochrome() {
+        let dark = Theme::palette(&Palette::Dark);
+        let monochrome = Theme::palette(&Palette::Monochrome);
+
+        assert_eq!(Theme::with_no_color(&Palette::Dark, false).base, dark.base);
+
+        let theme = Theme::with_no_color(&Palette::Dark, true);
+        assert_eq!(theme.base, monochrome.base);
+        assert_eq!(theme.menu_selected, monochrome.menu_selected);
+        assert_eq!(theme.error, monochrome.error);
+    }
+}
@@ -1,6 +1,12 @@
-use ratatui::style::{Style, Stylize};
+use ratatui::layout::{Margin, Position, Rect};
 use ratatui::text::{Line, Span};
+use ratatui::widgets::{Paragraph, Wrap};
 use std::fmt::Write;
+use std::mem::{discriminant, Discriminant};
+use strum::IntoEnumIterator;
+
+// Const for the lines above the map details, the heading and a blank line
+const MAP_HEADING: [&str; 2] = ["Map", ""];
 
 // Struct for Viewport
 pub struct Viewport {
@@ -13,6 +19,27 @@ pub struct Viewport {
     list_of_rooms: String,
     list_of_npcs: String,
     list_of_containers: String,
+    inventory_summary: String,
+    list_of_items: String,
+    item_details: String,
+    container_details: String,
+    shop_details: String,
+    tavern_details: String,
+    temple_details: String,
+    npc_details: String,
+    dialogue_details: String,
+    directory_details: String,
+    journal_details: String,
+    character_details: String,
+    map_details: String,
+    records_details: String,
+    keybindings_details: String,
+    themes_details: String,
+    map_links: Vec<(usize, String)>,
+    pub area: Rect,
+    pub scroll: u16,
+    pub max_scroll: u16,
+    scrolled_state: Option<Discriminant<crate::core::states::StateType>>,
 }
 
 // Functions for Viewport
@@ -29,11 +56,94 @@ impl Viewport {
             list_of_rooms: String::new(),
             list_of_npcs: String::new(),
             list_of_containers: String::new(),
+            inventory_summary: String::new(),
+            list_of_items: String::new(),
+            item_details: String::new(),
+            container_details: String::new(),
+            shop_details: String::new(),
+            tavern_details: String::new(),
+            temple_details: String::new(),
+            npc_details: String::new(),
+            directory_details: String::new(),
+            journal_details: String::new(),
+            character_details: String::new(),
+            map_details: String::new(),
+            records_details: String::new(),
+            keybindings_details: String::new(),
+            themes_details: String::new(),
+            map_links: Vec::new(),
+            area: Rect::default(),
+            scroll: 0,
+            max_scroll: 0,
+            scrolled_state: None,
+            dialogue_details: String::new(),
+        }
+    }
+
+    // Scroll the viewport up a line
+    pub fn scroll_up(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1);
+    }
+
+    // Scroll the viewport down a line, as far as the last line
+    pub fn scroll_down(&mut self) {
+        self.scroll = (self.scroll + 1).min(self.max_scroll);
+    }
+
+    // Whether a position is over the viewport
+    pub fn contains(&self, column: u16, row: u16) -> bool {
+        self.area.contains(Position::new(column, row))
+    }
+
+    // Title of the viewport, showing when there's more to scroll to
+    pub fn title(&self) -> String {
+        match (self.scroll > 0, self.scroll < self.max_scroll) {
+            (true, true) => "Viewport (more above and below)".into(),
+            (true, false) => "Viewport (more above)".into(),
+            (false, true) => "Viewport (more below)".into(),
+            (false, false) => "Viewport".into(),
+        }
+    }
+
+    // Town on the map at a clicked position, if there is one
+    pub fn map_town_at(&self, column: u16, row: u16) -> Option<&String> {
+        let text_area = self.area.inner(Margin::new(1, 1));
+
+        if !text_area.contains(Position::new(column, row)) {
+            return None;
         }
+
+        // Count the rows scrolled out of view, then find the line covering the clicked row as
+        // long lines wrap on words over several rows
+        let clicked_row = (row - text_area.y + self.scroll) as usize;
+        let mut rows = 0;
+        let line = MAP_HEADING
+            .iter()
+            .copied()
+            .chain(self.map_details.lines())
+            .position(|line| {
+                rows += Paragraph::new(line)
+                    .wrap(Wrap { trim: false })
+                    .line_count(text_area.width);
+                rows > clicked_row
+            })?
+            .checked_sub(MAP_HEADING.len())?;
+
+        self.map_links
+            .iter()
+            .find(|(link_line, _)| *link_line == line)
+            .map(|(_, town)| town)
     }
 
     // Updates any dynamic parts of Viewport
     pub fn update(&mut self, managers: &super::display::Managers) {
+        // Every screen starts scrolled to the top
+        let state = discriminant(&managers.state_manager.current_state);
+        if self.scrolled_state != Some(state) {
+            self.scroll = 0;
+            self.scrolled_state = Some(state);
+        }
+
         match managers.state_manager.current_state {
             // Game, Travel Town, Travel Building, Building, and Room
             crate::core::states::StateType::Game
@@ -145,10 +255,18 @@ impl Viewport {
                 if let Some(player) = managers.world_manager.player.as_ref() {
                     if let Some(current_room_id) = player.current_room_id.as_ref() {
                         if let Some(world) = managers.world_manager.world.as_ref() {
-                            if let Some(room) = world.rooms.get(current_room_id) {
-                                for npc in &room.npcs {
+                            if world.rooms.contains_key(current_room_id) {
+                                let npcs = managers
+                                    .world_manager
+                                    .npcs_in_room(*current_room_id, &managers.time_manager.phase());
+
+                                for npc in &npcs {
                                     writeln!(self.list_of_npcs, "{}", npc.name).unwrap();
                                 }
+
+                                if npcs.is_empty() {
+                                    self.list_of_npcs = "Nobody is here.".into();
+                                }
                             } else {
                                 self.list_of_npcs = "Failed to get NPCs.".into();
                             }
@@ -169,12 +287,31 @@ impl Viewport {
                         if let Some(world) = managers.world_manager.world.as_ref() {
                             if let Some(room) = world.rooms.get(current_room_id) {
                                 for container in &room.containers {
-                                    writeln!(
-                                        self.list_of_containers,
-                                        "{:?}",
-                                        container.container_type
-                                    )
-                                    .unwrap();
+                                    if managers.world_manager.is_container_locked(container.id) {
+                                        writeln!(
+                                            self.list_of_containers,
+                                            "{:?} (Locked)",
+                                            container.container_type
+                                        )
+                                        .unwrap();
+                                    } else if managers
+                                        .world_manager
+                                        .is_container_empty(container.id)
+                                    {
+                                        writeln!(
+                                            self.list_of_containers,
+                                            "{:?} (Empty)",
+                                            container.container_type
+                                        )
+                                        .unwrap();
+                                    } else {
+                                        writeln!(
+                                            self.list_of_containers,
+                                            "{:?}",
+                                            container.container_type
+                                        )
+                                        .unwrap();
+                                    }
                                 }
                             } else {
                                 self.list_of_containers = "Failed to get containers.".into();
@@ -225,13 +362,891 @@ impl Viewport {
                     self.weather = "GameWeather unavailable".into()
                 }
             }
+            // Container
+            crate::core::states::StateType::Container => {
+                // Get container type and contents
+                self.container_details.clear();
+                if let Some(container_id) = managers.state_manager.selected_container_id {
+                    if let Some(world) = managers.world_manager.world.as_ref() {
+                        if let Some(container) = world.containers.get(&container_id) {
+                            writeln!(
+                                self.container_details,
+                                "You open the {:?}.",
+                                container.container_type
+                            )
+                            .unwrap();
+                            writeln!(self.container_details).unwrap();
+                        }
+                    }
+
+                    match managers.world_manager.container_contents.get(&container_id) {
+                        Some(contents) if !contents.is_empty() => {
+                            writeln!(self.container_details, "Inside you find:").unwrap();
+                            writeln!(self.container_details).unwrap();
+                            for slot in contents {
+                                writeln!(self.container_details, "{}", slot.label()).unwrap();
+                            }
+                        }
+                        Some(_) => {
+                            writeln!(self.container_details, "It's empty.").unwrap();
+                        }
+                        None => {
+                            self.container_details = "Failed to get container.".into();
+                        }
+                    }
+                } else {
+                    self.container_details = "Failed to get container.".into();
+                }
+            }
+            // Lockpick
+            crate::core::states::StateType::Lockpick => {
+                // Get lock details
+                self.container_details.clear();
+                if let Some(container) =
+                    managers
+                        .state_manager
+                        .selected_container_id
+                        .and_then(|container_id| {
+                            managers
+                                .world_manager
+                                .world
+                                .as_ref()
+                                .and_then(|world| world.containers.get(&container_id))
+                        })
+                {
+                    writeln!(
+                        self.container_details,
+                        "The {:?} is locked.",
+                        container.container_type
+                    )
+                    .unwrap();
+                    writeln!(self.container_details).unwrap();
+                    writeln!(
+                        self.container_details,
+                        "Lock: {}",
+                        container.lock_description()
+                    )
+                    .unwrap();
+
+                    if let Some(player) = managers.world_manager.player.as_ref() {
+                        writeln!(
+                            self.container_details,
+                            "Lockpicks: {}",
+                            player.inventory.count(crate::entities::item::ITEM_LOCKPICK)
+                        )
+                        .unwrap();
+                    }
+
+                    writeln!(self.container_details).unwrap();
+                    writeln!(
+                        self.container_details,
+                        "Each attempt takes time, and anyone in the room might see you."
+                    )
+                    .unwrap();
+                } else {
+                    self.container_details = "Failed to get container.".into();
+                }
+            }
+            // Shop, Shop (Buy), and Shop (Sell)
+            crate::core::states::StateType::Shop
+            | crate::core::states::StateType::ShopBuy
+            | crate::core::states::StateType::ShopSell => {
+                // Get shopkeeper and prices
+                self.shop_details.clear();
+                if let Some(player) = managers.world_manager.player.as_ref() {
+                    let shopkeeper = player
+                        .current_building_id
+                        .and_then(|building_id| managers.world_manager.proprietor(building_id))
+                        .map(|npc| npc.name.clone())
+                        .unwrap_or_else(|| "The shopkeeper".into());
+
+                    let bounty = player
+                        .bounties
+                        .get(&player.current_town_id)
+                        .copied()
+                        .unwrap_or(0);
+
+                    if bounty > 0 {
+                        writeln!(
+                            self.shop_details,
+                            "{} eyes you warily: \"I don't trade with criminals. Settle your bounty first.\"",
+                            shopkeeper
+                        )
+                        .unwrap();
+                    } else {
+                        writeln!(
+                            self.shop_details,
+                            "{} greets you: \"Take a look, everything's for sale.\"",
+                            shopkeeper
+                        )
+                        .unwrap();
+                    }
+
+                    writeln!(self.shop_details).unwrap();
+                    writeln!(self.shop_details, "Your gold: {}", player.gold).unwrap();
+                    writeln!(
+                        self.shop_details,
+                        "Prices in this town: {}%",
+                        crate::world::shop::price_modifier(player.current_town_id)
+                    )
+                    .unwrap();
+                } else {
+                    self.shop_details = "Failed to get shop.".into();
+                }
+            }
+            // Tavern, Tavern (Coach), and Tavern (Notice Board)
+            crate::core::states::StateType::Tavern
+            | crate::core::states::StateType::TavernCoach
+            | crate::core::states::StateType::TavernBoard => {
+                // Get landlord and tavern details
+                self.tavern_details.clear();
+                if let Some(player) = managers.world_manager.player.as_ref() {
+                    let landlord = player
+                        .current_building_id
+                        .and_then(|building_id| managers.world_manager.proprietor(building_id))
+                        .map(|npc| npc.name.clone())
+                        .unwrap_or_else(|| "The landlord".into());
+
+                    match managers.state_manager.current_state {
+                        crate::core::states::StateType::TavernCoach => {
+                            writeln!(
+                                self.tavern_details,
+                                "{} points outside: \"The coach is twice as fast as walking.\"",
+                                landlord
+                            )
+                            .unwrap();
+                        }
+                        crate::core::states::StateType::TavernBoard => {
+                            writeln!(
+                                self.tavern_details,
+                                "A notice board by the door is covered in requests:"
+                            )
+                            .unwrap();
+
+                            let quests = player
+                                .current_building_id
+                                .and_then(|building_id| {
+                                    managers.world_manager.quest_boards.get(&building_id)
+                                })
+                                .map(|board| board.quests.as_slice())
+                                .unwrap_or_default();
+
+                            for log in quests {
+                                writeln!(self.tavern_details).unwrap();
+                                writeln!(self.tavern_details, "{}", log.title).unwrap();
+                                writeln!(self.tavern_details, "{}", log.description).unwrap();
+                            }
+
+                            if quests.is_empty() {
+                                writeln!(self.tavern_details).unwrap();
+                                writeln!(self.tavern_details, "It's been picked clean.").unwrap();
+                            }
+                        }
+                        _ => {
+                            writeln!(
+                                self.tavern_details,
+                                "{} wipes down the bar: \"What can I get you?\"",
+                                landlord
+                            )
+                            .unwrap();
+                        }
+                    }
+
+                    writeln!(self.tavern_details).unwrap();
+                    writeln!(self.tavern_details, "Your gold: {}", player.gold).unwrap();
+                } else {
+                    self.tavern_details = "Failed to get tavern.".into();
+                }
+            }
+            // NPC
+            crate::core::states::StateType::Npc => {
+                // Get NPC details and what the player knows about them
+                self.npc_details.clear();
+                if let (Some(player), Some(world), Some(npc_id)) = (
+                    managers.world_manager.player.as_ref(),
+                    managers.world_manager.world.as_ref(),
+                    managers.state_manager.selected_npc_id,
+                ) {
+                    if let Some(npc) = world.npcs.get(&npc_id) {
+                        writeln!(self.npc_details, "{}", npc.name).unwrap();
+                        writeln!(self.npc_details).unwrap();
+                        writeln!(self.npc_details, "Race: {:?}", npc.race).unwrap();
+                        writeln!(self.npc_details, "Sex: {:?}", npc.sex).unwrap();
+
+                        let disposition = player.disposition(npc_id);
+                        writeln!(
+                            self.npc_details,
+                            "Disposition: {} ({})",
+                            crate::entities::player::standing_label(disposition),
+                            disposition
+                        )
+                        .unwrap();
+
+                        match world.buildings.get(&npc.building_id) {
+                            Some(building) => {
+                                let town_name = world
+                                    .towns
+                                    .get(&building.town_id)
+                                    .map(|town| town.name.clone())
+                                    .unwrap_or_default();
+
+                                writeln!(
+                                    self.npc_details,
+                                    "Home: {}, {}",
+                                    building.name, town_name
+                                )
+                                .unwrap();
+                                writeln!(
+                                    self.npc_details,
+                                    "Role: {}",
+                                    building.building_type.npc_role()
+                                )
+                                .unwrap();
+                            }
+                            None => {
+                                writeln!(self.npc_details, "Home: Unknown").unwrap();
+                            }
+                        }
+
+                        writeln!(self.npc_details).unwrap();
+                        writeln!(self.npc_details, "What you know:").unwrap();
+
+                        match player.npc_notes.get(&npc_id) {
+                            Some(notes) if !notes.is_empty() => {
+                                for note in notes {
+                                    writeln!(self.npc_details, "- {}", note).unwrap();
+                                }
+                            }
+                            _ => {
+                                writeln!(self.npc_details, "Nothing yet.").unwrap();
+                            }
+                        }
+                    } else {
+                        self.npc_details = "Failed to get NPC.".into();
+                    }
+                } else {
+                    self.npc_details = "Failed to get NPC.".into();
+                }
+            }
+            // Directory
+            crate::core::states::StateType::Directory => {
+                // Get the search query and number of matches
+                self.directory_details.clear();
+                let matches = managers
+                    .world_manager
+                    .search_npcs(&managers.state_manager.search_query)
+                    .len();
+
+                writeln!(
+                    self.directory_details,
+                    "Search: {}_",
+                    managers.state_manager.search_query
+                )
+                .unwrap();
+                writeln!(self.directory_details).unwrap();
+                writeln!(self.directory_details, "{} people found.", matches).unwrap();
+            }
+            // Directory (NPC)
+            crate::core::states::StateType::DirectoryNpc => {
+                // Get NPC details and where they are right now
+                self.directory_details.clear();
+                if let (Some(world), Some(npc_id)) = (
+                    managers.world_manager.world.as_ref(),
+                    managers.state_manager.selected_npc_id,
+                ) {
+                    if let Some(npc) = world.npcs.get(&npc_id) {
+                        writeln!(self.directory_details, "{}", npc.name).unwrap();
+                        writeln!(self.directory_details).unwrap();
+                        writeln!(self.directory_details, "Race: {:?}", npc.race).unwrap();
+                        writeln!(self.directory_details, "Sex: {:?}", npc.sex).unwrap();
+
+                        if let Some(building) = world.buildings.get(&npc.building_id) {
+                            let town_name = world
+                                .towns
+                                .get(&building.town_id)
+                                .map(|town| town.name.clone())
+                                .unwrap_or_default();
+
+                            writeln!(
+                                self.directory_details,
+                                "Home: {}, {}",
+                                building.name, town_name
+                            )
+                            .unwrap();
+                        }
+
+                        let (building_id, room_id) = crate::world::schedule::npc_location(
+                            world,
+                            npc,
+                            &managers.time_manager.phase(),
+                        );
+
+                        match world.buildings.get(&building_id) {
+                            Some(building) => {
+                                let town_name = world
+                                    .towns
+                                    .get(&building.town_id)
+                                    .map(|town| town.name.clone())
+                                    .unwrap_or_default();
+
+                                writeln!(
+                                    self.directory_details,
+                                    "Now: {}, room {}, {}",
+                                    building.name, room_id, town_name
+                                )
+                                .unwrap();
+                            }
+                            None => {
+                                writeln!(self.directory_details, "Now: Unknown").unwrap();
+                            }
+                        }
+
+                        if let Some(route) = managers.state_manager.plotted_route.as_ref() {
+                            writeln!(self.directory_details).unwrap();
+                            writeln!(self.directory_details, "Route:").unwrap();
+                            for step in route.lines() {
+                                writeln!(self.directory_details, "- {}", step).unwrap();
+                            }
+                        }
+                    } else {
+                        self.directory_details = "Failed to get NPC.".into();
+                    }
+                } else {
+                    self.directory_details = "Failed to get NPC.".into();
+                }
+            }
+            // Dialogue
+            crate::core::states::StateType::Dialogue => {
+                // Get what the NPC is saying
+                self.dialogue_details.clear();
+                if let Some(context) = managers.state_manager.selected_npc_id.and_then(|npc_id| {
+                    crate::world::dialogue::DialogueContext::new(
+                        &managers.world_manager,
+                        &managers.time_manager,
+                        &managers.weather_manager,
+                        npc_id,
+                    )
+                }) {
+                    writeln!(self.dialogue_details, "{}:", context.npc.name).unwrap();
+                    writeln!(self.dialogue_details).unwrap();
+
+                    if let Some(text) = managers.dialogue_manager.current_text(&context) {
+                        writeln!(self.dialogue_details, "\"{}\"", text).unwrap();
+                    }
+
+                    if let Some(conversation) = managers.dialogue_manager.conversation.as_ref() {
+                        if !conversation.notes.is_empty() {
+                            writeln!(self.dialogue_details).unwrap();
+                        }
+
+                        for note in &conversation.notes {
+                            writeln!(self.dialogue_details, "{}", note).unwrap();
+                        }
+                    }
+                } else {
+                    self.dialogue_details = "Failed to get NPC.".into();
+                }
+            }
+            // Temple and Temple (Blessing)
+            crate::core::states::StateType::Temple
+            | crate::core::states::StateType::TempleBlessing => {
+                // Get priest, condition, standing and blessings
+                self.temple_details.clear();
+                if let Some(player) = managers.world_manager.player.as_ref() {
+                    let priest = player
+                        .current_building_id
+                        .and_then(|building_id| managers.world_manager.proprietor(building_id))
+                        .map(|npc| npc.name.clone())
+                        .unwrap_or_else(|| "The priest".into());
+
+                    writeln!(
+                        self.temple_details,
+                        "{} bows their head: \"Peace be with you, traveller.\"",
+                        priest
+                    )
+                    .unwrap();
+                    writeln!(self.temple_details).unwrap();
+                    writeln!(self.temple_details, "Your gold: {}", player.gold).unwrap();
+                    writeln!(
+                        self.temple_details,
+                        "Your health: {}",
+                        player.condition.describe()
+                    )
+                    .unwrap();
+                    let reputation = player.reputation(player.current_town_id);
+                    writeln!(
+                        self.temple_details,
+                        "Your standing in this town: {} ({})",
+                        crate::entities::player::standing_label(reputation),
+                        reputation
+                    )
+                    .unwrap();
+
+                    let day = managers
+                        .time_manager
+                        .now()
+                        .map(|time| time.day)
+                        .unwrap_or(1);
+                    let blessings: Vec<String> = player
+                        .blessings
+                        .iter()
+                        .filter(|blessing| blessing.expires_day > day)
+                        .map(|blessing| {
+                            format!(
+                                "{} (until day {})",
+                                blessing.blessing_type.name(),
+                                blessing.expires_day
+                            )
+                        })
+                        .collect();
+
+                    if blessings.is_empty() {
+                        writeln!(self.temple_details, "Blessings: None").unwrap();
+                    } else {
+                        writeln!(self.temple_details, "Blessings: {}", blessings.join(", "))
+                            .unwrap();
+                    }
+
+                    let return_point = player
+                        .respawn_building_id
+                        .and_then(|building_id| {
+                            managers
+                                .world_manager
+                                .world
+                                .as_ref()
+                                .and_then(|world| world.buildings.get(&building_id))
+                        })
+                        .map(|building| building.name.clone())
+                        .unwrap_or_else(|| "None".into());
+                    writeln!(self.temple_details, "Return point: {}", return_point).unwrap();
+                } else {
+                    self.temple_details = "Failed to get temple.".into();
+                }
+            }
+            // Inventory
+            crate::core::states::StateType::Inventory => {
+                // Get inventory summary and list of items
+                self.list_of_items.clear();
+                if let Some(player) = managers.world_manager.player.as_ref() {
+                    self.inventory_summary = format!(
+                        "Gold: {}, Weight: {}/{}, Slots: {}/{}",
+                        player.gold,
+                        player.inventory.total_weight(),
+                        player.inventory.max_weight,
+                        player.inventory.slots.len(),
+                        player.inventory.max_slots
+                    );
+
+                    for slot in &player.inventory.slots {
+                        writeln!(
+                            self.list_of_items,
+                            "{} ({:?})",
+                            slot.label(),
+                            slot.item.category
+                        )
+                        .unwrap();
+                    }
+
+                    if player.inventory.slots.is_empty() {
+                        self.list_of_items = "Your pack is empty.".into();
+                    }
+                } else {
+                    self.inventory_summary = "Error getting inventory info!".into();
+                }
+            }
+            // Journal
+            crate::core::states::StateType::Journal => {
+                // Get how many quests are on the go
+                self.journal_details.clear();
+                if let Some(player) = managers.world_manager.player.as_ref() {
+                    let completed = player
+                        .journal
+                        .iter()
+                        .filter(|log| log.is_complete())
+                        .count();
+
+                    writeln!(
+                        self.journal_details,
+                        "Active: {}, Completed: {}",
+                        player.journal.len() - completed,
+                        completed
+                    )
+                    .unwrap();
+
+                    if player.journal.is_empty() {
+                        writeln!(self.journal_details).unwrap();
+                        writeln!(
+                            self.journal_details,
+                            "You haven't taken on any quests. Try talking to people."
+                        )
+                        .unwrap();
+                    }
+                } else {
+                    self.journal_details = "Error getting journal info!".into();
+                }
+            }
+            // Journal (Quest)
+            crate::core::states::StateType::JournalQuest => {
+                // Get quest details and objectives
+                self.journal_details.clear();
+                if let (Some(player), Some(world), Some(index)) = (
+                    managers.world_manager.player.as_ref(),
+                    managers.world_manager.world.as_ref(),
+                    managers.state_manager.selected_quest_index,
+                ) {
+                    if let Some(log) = player.journal.get(index) {
+                        let giver = world
+                            .npcs
+                            .get(&log.giver_id)
+                            .map(|npc| npc.name.clone())
+                            .unwrap_or_else(|| "Unknown".into());
+
+                        writeln!(self.journal_details, "{}", log.title).unwrap();
+                        writeln!(self.journal_details).unwrap();
+                        writeln!(self.journal_details, "{}", log.description).unwrap();
+                        writeln!(self.journal_details).unwrap();
+                        writeln!(self.journal_details, "Given by: {}", giver).unwrap();
+                        writeln!(self.journal_details, "Reward: {}", log.reward.describe())
+                            .unwrap();
+                        writeln!(self.journal_details).unwrap();
+                        writeln!(self.journal_details, "Objectives:").unwrap();
+
+                        for (step, objective) in log.objectives.iter().enumerate() {
+                            let mark = if step < log.step {
+                                "[x]"
+                            } else if step == log.step {
+                                "[>]"
+                            } else {
+                                "[ ]"
+                            };
+
+                            writeln!(
+                                self.journal_details,
+                                "{} {}",
+                                mark,
+                                objective.describe(world)
+                            )
+                            .unwrap();
+                        }
+                    } else {
+                        self.journal_details = "Failed to get quest.".into();
+                    }
+                } else {
+                    self.journal_details = "Failed to get quest.".into();
+                }
+            }
+            // Map
+            crate::core::states::StateType::Map => {
+                // Get the towns the player has discovered and the roads out of them
+                self.map_details.clear();
+                self.map_links.clear();
+                if let (Some(player), Some(world)) = (
+                    managers.world_manager.player.as_ref(),
+                    managers.world_manager.world.as_ref(),
+                ) {
+                    writeln!(
+                        self.map_details,
+                        "World explored: {}%",
+                        managers.world_manager.world_exploration()
+                    )
+                    .unwrap();
+
+                    // Sorted so the map reads the same every time
+                    let mut towns: Vec<&crate::world::manager::Town> = world
+                        .towns
+                        .values()
+                        .filter(|town| player.visited_towns.contains(&town.id))
+                        .collect();
+                    towns.sort_by(|a, b| a.name.cmp(&b.name));
+
+                    let current_town = world
+                        .towns
+                        .get(&player.current_town_id)
+                        .map(|town| &town.name);
+
+                    for town in &towns {
+                        writeln!(self.map_details).unwrap();
+
+                        // Other towns can be clicked to travel there
+                        if town.id != player.current_town_id {
+                            self.map_links
+                                .push((self.map_details.lines().count(), town.name.clone()));
+                        }
+
+                        writeln!(
+                            self.map_details,
+                            "{}{} - {}% explored",
+                            town.name,
+                            if town.id == player.current_town_id {
+                                " (you are here)"
+                            } else {
+                                ""
+                            },
+                            managers.world_manager.town_exploration(town.id)
+                        )
+                        .unwrap();
+
+                        // Roads lead somewhere, but unvisited towns stay hidden
+                        for (destination, miles) in managers.world_manager.get_roads(&town.name) {
+                            let discovered = towns.iter().any(|town| town.name == destination);
+
+                            if discovered && Some(&destination) != current_town {
+                                self.map_links
+                                    .push((self.map_details.lines().count(), destination.clone()));
+                            }
+
+                            writeln!(
+                                self.map_details,
+                                "  Road to {}, {} miles",
+                                if discovered {
+                                    destination.as_str()
+                                } else {
+                                    crate::ui::menu::UNDISCOVERED_TOWN
+                                },
+                                miles
+                            )
+                            .unwrap();
+                        }
+                    }
+
+                    writeln!(self.map_details).unwrap();
+                    writeln!(
+                        self.map_details,
+                        "{} towns remain undiscovered.",
+                        world.towns.len() - towns.len()
+                    )
+                    .unwrap();
+                } else {
+                    self.map_details = "Error getting map info!".into();
+                }
+            }
+            // Records
+            crate::core::states::StateType::Records => {
+                // Get statistics for this game and every game, and the achievements earned
+                self.records_details.clear();
+                let records = &managers.records_manager;
+
+                let game = &records.game.statistics;
+                let lifetime = &records.lifetime.statistics;
+
+                writeln!(self.records_details, "Statistics (this game / all games):").unwrap();
+                writeln!(
+                    self.records_details,
+                    "Miles travelled: {} / {}",
+                    game.miles_travelled, lifetime.miles_travelled
+                )
+                .unwrap();
+                writeln!(
+                    self.records_details,
+                    "Gold spent: {} / {}",
+                    game.gold_spent, lifetime.gold_spent
+                )
+                .unwrap();
+                writeln!(
+                    self.records_details,
+                    "Days survived: {} / {}",
+                    game.days_survived, lifetime.days_survived
+                )
+                .unwrap();
+                writeln!(
+                    self.records_details,
+                    "NPCs met: {} / {}",
+                    game.npcs_met.len(),
+                    lifetime.npcs_met.len()
+                )
+                .unwrap();
+                writeln!(
+                    self.records_details,
+                    "Containers opened: {} / {}",
+                    game.containers_opened.len(),
+                    lifetime.containers_opened.len()
+                )
+                .unwrap();
+                writeln!(
+                    self.records_details,
+                    "Weather experienced: {} / {}",
+                    game.describe_weather(),
+                    lifetime.describe_weather()
+                )
+                .unwrap();
+                writeln!(self.records_details).unwrap();
+                writeln!(self.records_details, "Achievements:").unwrap();
+
+                for achievement in crate::core::records::Achievement::iter() {
+                    let earned = if records.game.achievements.contains(&achievement) {
+                        " (Earned)"
+                    } else if records.lifetime.achievements.contains(&achievement) {
+                        " (Earned in a past game)"
+                    } else {
+                        ""
+                    };
+
+                    writeln!(
+                        self.records_details,
+                        "{} - {}{}",
+                        achievement.name(),
+                        achievement.description(),
+                        earned
+                    )
+                    .unwrap();
+                }
+            }
+            // Keybindings
+            crate::core::states::StateType::Keybindings => {
+                // Get the active preset and the keys bound to each action
+                self.keybindings_details.clear();
+                let config = &managers.config_manager;
+
+                writeln!(
+                    self.keybindings_details,
+                    "Preset: {}",
+                    config.config.keymap.preset.name()
+                )
+                .unwrap();
+                writeln!(self.keybindings_details).unwrap();
+
+                for action in crate::core::keymap::Action::iter() {
+                    writeln!(
+                        self.keybindings_details,
+                        "{}: {}",
+                        action.name(),
+                        config.keymap.describe(&action, false)
+                    )
+                    .unwrap();
+                }
+
+                writeln!(self.keybindings_details).unwrap();
+                writeln!(
+                    self.keybindings_details,
+                    "Keys can be changed per action in config/config.json."
+                )
+                .unwrap();
+            }
+            // Themes
+            crate::core::states::StateType::Themes => {
+                // Get the active palette, and whether NO_COLOR is overriding it
+                self.themes_details.clear();
+
+                writeln!(
+                    self.themes_details,
+                    "Theme: {}",
+                    managers.config_manager.config.theme.name()
+                )
+                .unwrap();
+
+                if crate::ui::theme::no_color() {
+                    writeln!(self.themes_details).unwrap();
+                    writeln!(
+                        self.themes_details,
+                        "NO_COLOR is set, so the Monochrome theme is used instead."
+                    )
+                    .unwrap();
+                }
+
+                writeln!(self.themes_details).unwrap();
+                writeln!(
+                    self.themes_details,
+                    "The theme is kept in config/config.json."
+                )
+                .unwrap();
+            }
+            // Character
+            crate::core::states::StateType::Character => {
+                // Get level, attributes and skills
+                self.character_details.clear();
+                if let Some(player) = managers.world_manager.player.as_ref() {
+                    let progression = &player.progression;
+
+                    writeln!(
+                        self.character_details,
+                        "Level {} ({}/{} XP)",
+                        progression.level,
+                        progression.experience,
+                        progression.next_level_experience()
+                    )
+                    .unwrap();
+                    writeln!(
+                        self.character_details,
+                        "Attribute points: {}, Skill points: {}",
+                        progression.attribute_points, progression.skill_points
+                    )
+                    .unwrap();
+                    writeln!(self.character_details).unwrap();
+                    writeln!(self.character_details, "Attributes:").unwrap();
+
+                    for attribute in crate::entities::attributes::Attribute::ALL.iter() {
+                        writeln!(
+                            self.character_details,
+                            "{} {} - {}",
+                            attribute.name(),
+                            player.attributes.score(attribute),
+                            attribute.effect()
+                        )
+                        .unwrap();
+                    }
+
+                    writeln!(self.character_details).unwrap();
+                    writeln!(self.character_details, "Skills:").unwrap();
+
+                    for skill in crate::entities::progression::Skill::ALL.iter() {
+                        let rank = progression.rank(skill);
+
+                        // Later ranks need a higher level
+                        let next = if rank >= crate::entities::progression::MAX_SKILL_RANK {
+                            String::new()
+                        } else {
+                            format!(", next rank at level {}", progression.required_level(skill))
+                        };
+
+                        writeln!(
+                            self.character_details,
+                            "{} {}/{} - {}{}",
+                            skill.name(),
+                            rank,
+                            crate::entities::progression::MAX_SKILL_RANK,
+                            skill.effect(),
+                            next
+                        )
+                        .unwrap();
+                    }
+                } else {
+                    self.character_details = "Error getting character info!".into();
+                }
+            }
+            // Inventory Item
+            crate::core::states::StateType::InventoryItem => {
+                // Get item details
+                self.item_details.clear();
+                if let (Some(player), Some(index)) = (
+                    managers.world_manager.player.as_ref(),
+                    managers.state_manager.selected_item_index,
+                ) {
+                    if let Some(slot) = player.inventory.slots.get(index) {
+                        writeln!(self.item_details, "Name: {}", slot.item.name).unwrap();
+                        writeln!(self.item_details, "Category: {:?}", slot.item.category).unwrap();
+                        writeln!(self.item_details, "Quantity: {}", slot.quantity).unwrap();
+                        writeln!(self.item_details, "Weight: {}", slot.item.weight).unwrap();
+                        writeln!(self.item_details, "Value: {} gold", slot.item.value).unwrap();
+                        writeln!(
+                            self.item_details,
+                            "Stackable: {}",
+                            if slot.item.stackable { "Yes" } else { "No" }
+                        )
+                        .unwrap();
+                    } else {
+                        self.item_details = "Failed to get item.".into();
+                    }
+                } else {
+                    self.item_details = "Failed to get item.".into();
+                }
+            }
             // All other states
             _ => {}
         }
     }
 
     // Renders the Viewport based on current state
-    pub fn render(&self, managers: &super::display::Managers) -> Vec<Line> {
+    pub fn render(&self, managers: &super::display::Managers) -> Vec<Line<'_>> {
         match managers.state_manager.current_state {
             // Main Menu
             crate::core::states::StateType::MainMenu => {
@@ -247,7 +1262,7 @@ impl Viewport {
                     Line::from(""),
                     Line::from(vec![
                         Span::raw("Select "),
-                        Span::styled("New Game", Style::new().green().bold()),
+                        Span::styled("New Game", managers.config_manager.theme.highlight),
                         Span::raw(" when you're ready to begin."),
     ]),
                 ]
@@ -287,21 +1302,21 @@ impl Viewport {
             crate::core::states::StateType::GameSaveError => {
                 vec![Line::from(vec![Span::styled(
                     "Error saving game!",
-                    Style::new().red(),
+                    managers.config_manager.theme.error,
                 )])]
             }
             // Load Game (Error)
             crate::core::states::StateType::GameLoadError => {
                 vec![Line::from(vec![Span::styled(
                     "Error loading game!",
-                    Style::new().red(),
+                    managers.config_manager.theme.error,
                 )])]
             }
             // Initialize Game (Error)
             crate::core::states::StateType::GameInitError => {
                 vec![Line::from(vec![Span::styled(
                     "Error initializing game!",
-                    Style::new().red(),
+                    managers.config_manager.theme.error,
                 )])]
             }
             // Quit Game
@@ -370,6 +1385,289 @@ impl Viewport {
                 );
                 output_lines
             }
+            // Container and Lockpick
+            crate::core::states::StateType::Container
+            | crate::core::states::StateType::Lockpick => {
+                let mut output_lines = vec![
+                    Line::from(self.town_name.clone()),
+                    Line::from(""),
+                    Line::from(self.location.clone()),
+                    Line::from(""),
+                    Line::from(self.room_id.clone()),
+                    Line::from(""),
+                ];
+                output_lines.extend(
+                    self.container_details
+                        .lines()
+                        .map(|line| Line::from(line.to_string())),
+                );
+                output_lines
+            }
+            // Shop, Shop (Buy), and Shop (Sell)
+            crate::core::states::StateType::Shop
+            | crate::core::states::StateType::ShopBuy
+            | crate::core::states::StateType::ShopSell => {
+                let mut output_lines = vec![
+                    Line::from(self.town_name.clone()),
+                    Line::from(""),
+                    Line::from(self.location.clone()),
+                    Line::from(""),
+                ];
+                output_lines.extend(
+                    self.shop_details
+                        .lines()
+                        .map(|line| Line::from(line.to_string())),
+                );
+                output_lines.push(Line::from(""));
+                output_lines.push(Line::from(match managers.state_manager.current_state {
+                    crate::core::states::StateType::ShopBuy => "Select an item to buy...",
+                    crate::core::states::StateType::ShopSell => "Select an item to sell...",
+                    _ => "Select an option from the menu below...",
+                }));
+                output_lines
+            }
+            // Tavern, Tavern (Coach), and Tavern (Notice Board)
+            crate::core::states::StateType::Tavern
+            | crate::core::states::StateType::TavernCoach
+            | crate::core::states::StateType::TavernBoard => {
+                let mut output_lines = vec![
+                    Line::from(self.town_name.clone()),
+                    Line::from(""),
+                    Line::from(self.location.clone()),
+                    Line::from(""),
+                ];
+                output_lines.extend(
+                    self.tavern_details
+                        .lines()
+                        .map(|line| Line::from(line.to_string())),
+                );
+                output_lines.push(Line::from(""));
+                output_lines.push(Line::from(match managers.state_manager.current_state {
+                    crate::core::states::StateType::TavernCoach => "Select a town to travel to...",
+                    crate::core::states::StateType::TavernBoard => {
+                        "Select a request to take it on..."
+                    }
+                    _ => "Select an option from the menu below...",
+                }));
+                output_lines
+            }
+            // NPC
+            crate::core::states::StateType::Npc => {
+                let mut output_lines = vec![
+                    Line::from(self.town_name.clone()),
+                    Line::from(""),
+                    Line::from(self.location.clone()),
+                    Line::from(""),
+                ];
+                output_lines.extend(
+                    self.npc_details
+                        .lines()
+                        .map(|line| Line::from(line.to_string())),
+                );
+                output_lines.push(Line::from(""));
+                output_lines.push(Line::from("Select an option from the menu below..."));
+                output_lines
+            }
+            // Directory and Directory (NPC)
+            crate::core::states::StateType::Directory
+            | crate::core::states::StateType::DirectoryNpc => {
+                let mut output_lines = vec![Line::from("Directory"), Line::from("")];
+                output_lines.extend(
+                    self.directory_details
+                        .lines()
+                        .map(|line| Line::from(line.to_string())),
+                );
+                output_lines.push(Line::from(""));
+                output_lines.push(Line::from(match managers.state_manager.current_state {
+                    crate::core::states::StateType::Directory => {
+                        "Type to search by name, race or town..."
+                    }
+                    _ => "Select an option from the menu below...",
+                }));
+                output_lines
+            }
+            // Dialogue
+            crate::core::states::StateType::Dialogue => {
+                let mut output_lines = vec![
+                    Line::from(self.town_name.clone()),
+                    Line::from(""),
+                    Line::from(self.location.clone()),
+                    Line::from(""),
+                ];
+                output_lines.extend(
+                    self.dialogue_details
+                        .lines()
+                        .map(|line| Line::from(line.to_string())),
+                );
+                output_lines.push(Line::from(""));
+                output_lines.push(Line::from("Select a response..."));
+                output_lines
+            }
+            // Temple and Temple (Blessing)
+            crate::core::states::StateType::Temple
+            | crate::core::states::StateType::TempleBlessing => {
+                let mut output_lines = vec![
+                    Line::from(self.town_name.clone()),
+                    Line::from(""),
+                    Line::from(self.location.clone()),
+                    Line::from(""),
+                ];
+                output_lines.extend(
+                    self.temple_details
+                        .lines()
+                        .map(|line| Line::from(line.to_string())),
+                );
+                output_lines.push(Line::from(""));
+                output_lines.push(Line::from(match managers.state_manager.current_state {
+                    crate::core::states::StateType::TempleBlessing => {
+                        "Select a blessing to receive..."
+                    }
+                    _ => "Select an option from the menu below...",
+                }));
+                output_lines
+            }
+            // Inventory
+            crate::core::states::StateType::Inventory => {
+                let mut output_lines = vec![
+                    Line::from("Inventory"),
+                    Line::from(""),
+                    Line::from(self.inventory_summary.clone()),
+                    Line::from(""),
+                ];
+                output_lines.extend(
+                    self.list_of_items
+                        .lines()
+                        .map(|line| Line::from(line.to_string())),
+                );
+                output_lines.push(Line::from(""));
+                output_lines.push(Line::from("Select an item to inspect it..."));
+                output_lines
+            }
+            // Inventory Item
+            crate::core::states::StateType::InventoryItem => {
+                let mut output_lines = vec![Line::from("Item"), Line::from("")];
+                output_lines.extend(
+                    self.item_details
+                        .lines()
+                        .map(|line| Line::from(line.to_string())),
+                );
+                output_lines
+            }
+            // Journal and Journal (Quest)
+            crate::core::states::StateType::Journal
+            | crate::core::states::StateType::JournalQuest => {
+                let mut output_lines = vec![Line::from("Journal"), Line::from("")];
+                output_lines.extend(
+                    self.journal_details
+                        .lines()
+                        .map(|line| Line::from(line.to_string())),
+                );
+                output_lines.push(Line::from(""));
+                output_lines.push(Line::from(match managers.state_manager.current_state {
+                    crate::core::states::StateType::Journal => "Select a quest to read it...",
+                    _ => "Select an option from the menu below...",
+                }));
+                output_lines
+            }
+            // Records
+            crate::core::states::StateType::Records => {
+                let mut output_lines = vec![Line::from("Records"), Line::from("")];
+                output_lines.extend(
+                    self.records_details
+                        .lines()
+                        .map(|line| Line::from(line.to_string())),
+                );
+                output_lines
+            }
+            // Keybindings
+            crate::core::states::StateType::Keybindings => {
+                let mut output_lines = vec![Line::from("Keybindings"), Line::from("")];
+                output_lines.extend(
+                    self.keybindings_details
+                        .lines()
+                        .map(|line| Line::from(line.to_string())),
+                );
+                output_lines
+            }
+            // Themes
+            crate::core::states::StateType::Themes => {
+                let mut output_lines = vec![Line::from("Themes"), Line::from("")];
+                output_lines.extend(
+                    self.themes_details
+                        .lines()
+                        .map(|line| Line::from(line.to_string())),
+                );
+                output_lines
+            }
+            // Map and Travel Confirmation
+            crate::core::states::StateType::Map | crate::core::states::StateType::TravelConfirm => {
+                let mut output_lines: Vec<Line> =
+                    MAP_HEADING.iter().map(|&line| Line::from(line)).collect();
+                output_lines.extend(
+                    self.map_details
+                        .lines()
+                        .map(|line| Line::from(line.to_string())),
+                );
+                output_lines
+            }
+            // Character
+            crate::core::states::StateType::Character => {
+                let mut output_lines = vec![Line::from("Character"), Line::from("")];
+                output_lines.extend(
+                    self.character_details
+                        .lines()
+                        .map(|line| Line::from(line.to_string())),
+                );
+                output_lines.push(Line::from(""));
+                output_lines.push(Line::from("Spend your points from the menu below..."));
+                output_lines
+            }
+            // Notice
+            crate::core::states::StateType::Notice => {
+                vec![Line::from("")]
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A viewport showing a map with a long line that wraps above a town that can be clicked
+    fn narrow_map() -> Viewport {
+        let mut viewport = Viewport::new();
+        viewport.area = Rect::new(0, 0, 22, 20);
+        viewport.map_details = [
+            "World explored: 5%",
+            "",
+            "Ashford (you are here) - 10% explored",
+            "  Road to Brook, 12 miles",
+        ]
+        .join("\n");
+        viewport.map_links = vec![(3, "Brook".into())];
+        viewport
+    }
+
+    #[test]
+    fn clicking_a_town_below_a_wrapped_line_finds_it() {
+        let viewport = narrow_map();
+
+        // Border, heading, blank line, explored line, blank line, the current town wrapped over
+        // two rows and then the road to the town, also wrapped over two rows
+        assert_eq!(viewport.map_town_at(2, 6), None);
+        assert_eq!(viewport.map_town_at(2, 7), Some(&"Brook".to_string()));
+        assert_eq!(viewport.map_town_at(2, 8), Some(&"Brook".to_string()));
+        assert_eq!(viewport.map_town_at(2, 9), None);
+    }
+
+    #[test]
+    fn clicking_a_scrolled_map_counts_the_rows_out_of_view() {
+        let mut viewport = narrow_map();
+        viewport.scroll = 4;
+
+        assert_eq!(viewport.map_town_at(2, 3), Some(&"Brook".to_string()));
+        assert_eq!(viewport.map_town_at(2, 4), Some(&"Brook".to_string()));
+        assert_eq!(viewport.map_town_at(2, 0), None);
+    }
+}
@@ -0,0 +1,535 @@
+use crate::entities::player::{Player, QuestStatus};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+// Struct for Dialogue Manager
+pub struct DialogueManager {
+    dialogue: Option<Dialogue>,
+    pub conversation: Option<Conversation>,
+}
+
+// Functions for Dialogue Manager
+impl DialogueManager {
+    // Create a new Dialogue Manager
+    pub fn new() -> Self {
+        Self {
+            dialogue: None,
+            conversation: None,
+        }
+    }
+
+    // Load in dialogue JSON file
+    pub fn load_dialogue(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let dialogue_path = PathBuf::from("assets").join("dialogue.json");
+        let dialogue_data = fs::read_to_string(dialogue_path)?;
+        self.dialogue = Some(serde_json::from_str(&dialogue_data)?);
+
+        Ok(())
+    }
+
+    // Start a conversation with an NPC
+    // NPCs with an authored tree use it, everyone else gets a fallback line
+    pub fn start(&mut self, context: &DialogueContext, rng_manager: &crate::core::rng::RngManager) {
+        let dialogue = match self.dialogue.as_ref() {
+            Some(dialogue) => dialogue,
+            None => {
+                log::error!("Failed to start conversation: No dialogue loaded.");
+                return;
+            }
+        };
+
+        let tree = dialogue
+            .trees
+            .iter()
+            .find(|tree| tree.npc_ids.contains(&context.npc.id))
+            .or_else(|| {
+                dialogue.trees.iter().find(|tree| {
+                    tree.npc_ids.is_empty() && check_conditions(&tree.conditions, context)
+                })
+            });
+
+        self.conversation = Some(match tree {
+            Some(tree) => Conversation {
+                tree_id: Some(tree.id.clone()),
+                node_id: Some(tree.start.clone()),
+                fallback: None,
+                notes: Vec::new(),
+            },
+            None => {
+                // Pick one of the fallback lines that fits
+                let lines: Vec<&DialogueLine> = dialogue
+                    .fallback
+                    .iter()
+                    .filter(|line| check_conditions(&line.conditions, context))
+                    .collect();
+
+                let text = if lines.is_empty() {
+                    "They don't seem to want to talk.".into()
+                } else {
+                    lines[rng_manager.pick_index(lines.len())].text.clone()
+                };
+
+                Conversation {
+                    tree_id: None,
+                    node_id: None,
+                    fallback: Some(fill_placeholders(&text, context)),
+                    notes: Vec::new(),
+                }
+            }
+        });
+    }
+
+    // End the current conversation
+    pub fn end(&mut self) {
+        self.conversation = None;
+    }
+
+    // Get the current node of the conversation, if it's following a tree
+    fn current_node(&self) -> Option<&DialogueNode> {
+        let conversation = self.conversation.as_ref()?;
+        let tree_id = conversation.tree_id.as_ref()?;
+        let node_id = conversation.node_id.as_ref()?;
+
+        self.dialogue
+            .as_ref()?
+            .trees
+            .iter()
+            .find(|tree| tree.id == *tree_id)?
+            .nodes
+            .get(node_id)
+    }
+
+    // What the NPC says at the current node, the first line that fits
+    pub fn current_text(&self, context: &DialogueContext) -> Option<String> {
+        if let Some(fallback) = self.conversation.as_ref()?.fallback.as_ref() {
+            return Some(fallback.clone());
+        }
+
+        self.current_node()?
+            .lines
+            .iter()
+            .find(|line| check_conditions(&line.conditions, context))
+            .map(|line| fill_placeholders(&line.text, context))
+    }
+
+    // The responses the player can give at the current node
+    pub fn responses(&self, context: &DialogueContext) -> Vec<String> {
+        match self.current_node() {
+            Some(node) => node
+                .responses
+                .iter()
+                .filter(|response| check_conditions(&response.conditions, context))
+                .map(|response| fill_placeholders(&response.text, context))
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    // Choose a response by index, moves the conversation on and returns the effects to apply
+    // Returns None if there was no such response
+    pub fn choose(&mut self, index: usize, context: &DialogueContext) -> Option<Vec<Effect>> {
+        let response = self
+            .current_node()?
+            .responses
+            .iter()
+            .filter(|response| check_conditions(&response.conditions, context))
+            .nth(index)?
+            .clone();
+
+        if let Some(conversation) = self.conversation.as_mut() {
+            conversation.node_id = response.next;
+            conversation.notes.clear();
+        }
+
+        Some(
+            response
+                .effects
+                .into_iter()
+                .map(|effect| match effect {
+                    Effect::LearnFact(fact) => Effect::LearnFact(fill_placeholders(&fact, context)),
+                    _ => effect,
+                })
+                .collect(),
+        )
+    }
+}
+
+// Struct for a conversation in progress
+pub struct Conversation {
+    tree_id: Option<String>,
+    node_id: Option<String>,
+    fallback: Option<String>,
+    pub notes: Vec<String>,
+}
+
+// Functions for conversation
+impl Conversation {
+    // Check whether the conversation has run out of things to say
+    pub fn is_finished(&self) -> bool {
+        self.tree_id.is_some() && self.node_id.is_none()
+    }
+}
+
+// Struct for everything a dialogue condition can check
+pub struct DialogueContext<'a> {
+    pub player: &'a Player,
+    pub npc: &'a super::manager::Npc,
+    town_name: String,
+    building_name: String,
+    building_type: super::manager::BuildingType,
+    proprietor: bool,
+    phase: super::time::Phase,
+    weather: super::weather::WeatherType,
+}
+
+// Functions for dialogue context
+impl<'a> DialogueContext<'a> {
+    // Gather the context for talking to an NPC
+    pub fn new(
+        world_manager: &'a super::manager::WorldManager,
+        time_manager: &super::time::TimeManager,
+        weather_manager: &super::weather::WeatherManager,
+        npc_id: u32,
+    ) -> Option<Self> {
+        let player = world_manager.player.as_ref()?;
+        let world = world_manager.world.as_ref()?;
+        let npc = world.npcs.get(&npc_id)?;
+        let building = world.buildings.get(&npc.building_id)?;
+        let town = world.towns.get(&npc.town_id)?;
+
+        let proprietor = world_manager
+            .proprietor(building.id)
+            .map(|proprietor| proprietor.id == npc.id)
+            .unwrap_or(false);
+
+        let phase = time_manager.phase();
+        let weather = weather_manager
+            .weather_arc_rwlock
+            .as_ref()
+            .and_then(|game_weather| game_weather.read().ok().map(|w| w.weather_type.clone()))
+            .unwrap_or_default();
+
+        Some(Self {
+            player,
+            npc,
+            town_name: town.name.clone(),
+            building_name: building.name.clone(),
+            building_type: building.building_type.clone(),
+            proprietor,
+            phase,
+            weather,
+        })
+    }
+}
+
+// Struct for the dialogue file
+#[derive(Debug, Deserialize)]
+struct Dialogue {
+    trees: Vec<DialogueTree>,
+    fallback: Vec<DialogueLine>,
+}
+
+// Struct for a conversation tree
+#[derive(Debug, Deserialize)]
+struct DialogueTree {
+    id: String,
+    #[serde(default)]
+    npc_ids: Vec<u32>,
+    #[serde(default)]
+    conditions: Vec<Condition>,
+    start: String,
+    nodes: HashMap<String, DialogueNode>,
+}
+
+// Struct for a node in a conversation tree
+#[derive(Debug, Deserialize)]
+struct DialogueNode {
+    lines: Vec<DialogueLine>,
+    #[serde(default)]
+    responses: Vec<DialogueResponse>,
+}
+
+// Struct for a line of dialogue
+#[derive(Debug, Deserialize)]
+struct DialogueLine {
+    text: String,
+    #[serde(default)]
+    conditions: Vec<Condition>,
+}
+
+// Struct for a response the player can give
+#[derive(Debug, Clone, Deserialize)]
+struct DialogueResponse {
+    text: String,
+    #[serde(default)]
+    next: Option<String>,
+    #[serde(default)]
+    conditions: Vec<Condition>,
+    #[serde(default)]
+    effects: Vec<Effect>,
+}
+
+// Enum for dialogue conditions
+#[derive(Debug, Clone, Deserialize)]
+enum Condition {
+    Phase(super::time::Phase),
+    Weather(super::weather::WeatherType),
+    MinGold(u32),
+    MaxGold(u32),
+    Quest { quest: String, status: QuestStatus },
+    Race(super::manager::NpcRace),
+    Sex(super::manager::NpcSex),
+    BuildingType(super::manager::BuildingType),
+    Proprietor(bool),
+    MinReputation(i32),
+    MinDisposition(i32),
+    MaxDisposition(i32),
+    QuestGiver(bool),
+}
+
+// Functions for dialogue conditions
+impl Condition {
+    // Check the condition holds
+    fn check(&self, context: &DialogueContext) -> bool {
+        match self {
+            Condition::Phase(phase) => context.phase == *phase,
+            Condition::Weather(weather) => context.weather == *weather,
+            Condition::MinGold(gold) => context.player.gold >= *gold,
+            Condition::MaxGold(gold) => context.player.gold <= *gold,
+            Condition::Quest { quest, status } => context.player.quest_status(quest) == *status,
+            Condition::Race(race) => context.npc.race == *race,
+            Condition::Sex(sex) => context.npc.sex == *sex,
+            Condition::BuildingType(building_type) => context.building_type == *building_type,
+            Condition::Proprietor(proprietor) => context.proprietor == *proprietor,
+            Condition::MinReputation(reputation) => {
+                context.player.reputation(context.npc.town_id) >= *reputation
+            }
+            Condition::MinDisposition(disposition) => {
+                context.player.disposition(context.npc.id) >= *disposition
+            }
+            Condition::MaxDisposition(disposition) => {
+                context.player.disposition(context.npc.id) <= *disposition
+            }
+            Condition::QuestGiver(giver) => {
+                context
+                    .player
+                    .journal
+                    .iter()
+                    .any(|log| log.giver_id == context.npc.id && !log.is_complete())
+                    == *giver
+            }
+        }
+    }
+}
+
+// Enum for dialogue effects
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Effect {
+    GiveItem { item_id: u32, quantity: u32 },
+    TakeGold(u32),
+    StartQuest(String),
+    OfferQuest,
+    Reputation(i32),
+    Disposition(i32),
+    LearnFact(String),
+}
+
+// Check all conditions hold
+fn check_conditions(conditions: &[Condition], context: &DialogueContext) -> bool {
+    conditions.iter().all(|condition| condition.check(context))
+}
+
+// Fill in placeholders in a line of dialogue
+fn fill_placeholders(text: &str, context: &DialogueContext) -> String {
+    text.replace("{player}", &context.player.name)
+        .replace("{npc}", &context.npc.name)
+        .replace("{town}", &context.town_name)
+        .replace("{building}", &context.building_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::manager::{BuildingType, Npc, NpcRace, NpcSex};
+    use crate::world::time::Phase;
+    use crate::world::weather::WeatherType;
+
+    const DIALOGUE: &str = r#"{
+        "trees": [
+            {
+                "id": "friend",
+                "npc_ids": [2],
+                "start": "greeting",
+                "nodes": { "greeting": { "lines": [{ "text": "Good to see you, {player}." }] } }
+            },
+            {
+                "id": "innkeeper",
+                "conditions": [{ "BuildingType": "Tavern" }, { "Proprietor": true }],
+                "start": "greeting",
+                "nodes": {
+                    "greeting": {
+                        "lines": [
+                            { "text": "Bit late, isn't it?", "conditions": [{ "Phase": "Night" }] },
+                            { "text": "Mind the storm.", "conditions": [{ "Weather": "Stormy" }] },
+                            { "text": "Welcome to {building}." }
+                        ],
+                        "responses": [
+                            { "text": "Buy a round. (10 gold)", "next": "round", "conditions": [{ "MinGold": 10 }], "effects": [{ "TakeGold": 10 }] },
+                            { "text": "Tell me about {town}.", "effects": [{ "LearnFact": "Runs {building} in {town}." }] }
+                        ]
+                    },
+                    "round": { "lines": [{ "text": "Cheers!" }] }
+                }
+            }
+        ],
+        "fallback": [
+            { "text": "Evening, {player}.", "conditions": [{ "Phase": "Dusk" }] }
+        ]
+    }"#;
+
+    fn dialogue_manager() -> DialogueManager {
+        DialogueManager {
+            dialogue: Some(serde_json::from_str(DIALOGUE).unwrap()),
+            conversation: None,
+        }
+    }
+
+    fn rng_manager() -> crate::core::rng::RngManager {
+        let mut rng_manager = crate::core::rng::RngManager::new(Some(7));
+        rng_manager.start_new();
+        rng_manager
+    }
+
+    fn npc(id: u32) -> Npc {
+        Npc {
+            id,
+            name: "Ada".into(),
+            sex: NpcSex::Female,
+            race: NpcRace::Human,
+            town_id: 1,
+            building_id: 1,
+            room_id: None,
+        }
+    }
+
+    fn context<'a>(player: &'a Player, npc: &'a Npc, phase: Phase) -> DialogueContext<'a> {
+        DialogueContext {
+            player,
+            npc,
+            town_name: "Brook".into(),
+            building_name: "The Grey Goose".into(),
+            building_type: BuildingType::Tavern,
+            proprietor: true,
+            phase,
+            weather: WeatherType::Stormy,
+        }
+    }
+
+    #[test]
+    fn the_first_line_that_fits_is_said() {
+        let player = Player::new(1, "Bob".into(), 1, None, None);
+        let npc = npc(1);
+        let mut dialogue_manager = dialogue_manager();
+
+        let night = context(&player, &npc, Phase::Night);
+        dialogue_manager.start(&night, &rng_manager());
+        assert_eq!(
+            dialogue_manager.current_text(&night).as_deref(),
+            Some("Bit late, isn't it?")
+        );
+
+        let mut day = context(&player, &npc, Phase::Day);
+        assert_eq!(
+            dialogue_manager.current_text(&day).as_deref(),
+            Some("Mind the storm.")
+        );
+
+        day.weather = WeatherType::Sunny;
+        assert_eq!(
+            dialogue_manager.current_text(&day).as_deref(),
+            Some("Welcome to The Grey Goose.")
+        );
+    }
+
+    #[test]
+    fn responses_need_their_conditions_and_hand_back_effects() {
+        let mut player = Player::new(1, "Bob".into(), 1, None, None);
+        let npc = npc(1);
+        let mut dialogue_manager = dialogue_manager();
+
+        // Too poor to buy a round
+        player.gold = 5;
+        let context = context(&player, &npc, Phase::Day);
+        dialogue_manager.start(&context, &rng_manager());
+        assert_eq!(
+            dialogue_manager.responses(&context),
+            ["Tell me about Brook."]
+        );
+
+        let effects = dialogue_manager.choose(0, &context).unwrap();
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::LearnFact(fact)] if fact == "Runs The Grey Goose in Brook."
+        ));
+        assert!(dialogue_manager
+            .conversation
+            .as_ref()
+            .unwrap()
+            .is_finished());
+
+        // With the gold for it the round is offered and moves the conversation on
+        player.gold = 10;
+        let context = self::context(&player, &npc, Phase::Day);
+        dialogue_manager.start(&context, &rng_manager());
+        assert_eq!(dialogue_manager.responses(&context).len(), 2);
+
+        let effects = dialogue_manager.choose(0, &context).unwrap();
+        assert!(matches!(effects.as_slice(), [Effect::TakeGold(10)]));
+        assert_eq!(
+            dialogue_manager.current_text(&context).as_deref(),
+            Some("Cheers!")
+        );
+        assert!(dialogue_manager.choose(5, &context).is_none());
+    }
+
+    #[test]
+    fn npcs_with_their_own_tree_use_it_first() {
+        let player = Player::new(1, "Bob".into(), 1, None, None);
+        let npc = npc(2);
+        let mut dialogue_manager = dialogue_manager();
+
+        let context = context(&player, &npc, Phase::Night);
+        dialogue_manager.start(&context, &rng_manager());
+        assert_eq!(
+            dialogue_manager.current_text(&context).as_deref(),
+            Some("Good to see you, Bob.")
+        );
+    }
+
+    #[test]
+    fn everyone_else_gets_a_fallback_line() {
+        let player = Player::new(1, "Bob".into(), 1, None, None);
+        let npc = npc(1);
+        let mut dialogue_manager = dialogue_manager();
+
+        // Not the innkeeper, so no tree fits
+        let mut context = context(&player, &npc, Phase::Dusk);
+        context.proprietor = false;
+        dialogue_manager.start(&context, &rng_manager());
+        assert_eq!(
+            dialogue_manager.current_text(&context).as_deref(),
+            Some("Evening, Bob.")
+        );
+        assert!(dialogue_manager.responses(&context).is_empty());
+
+        // No fallback line fits either
+        context.phase = Phase::Day;
+        dialogue_manager.start(&context, &rng_manager());
+        assert_eq!(
+            dialogue_manager.current_text(&context).as_deref(),
+            Some("They don't seem to want to talk.")
+        );
+    }
+}
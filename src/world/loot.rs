@@ -0,0 +1,145 @@
+use crate::entities::inventory::InventorySlot;
+use crate::entities::item::{Item, ItemCategory};
+
+// Consts for loot tables per building type (item ID, max quantity)
+const LOOT_RESIDENCE: [(u32, u32); 8] = [
+    (1, 2),  // Bread
+    (3, 3),  // Apple
+    (6, 2),  // Water
+    (7, 2),  // Candle
+    (18, 2), // Cloth
+    (13, 1), // Dagger
+    (14, 1), // Gold Ring
+    (15, 1), // Silver Necklace
+];
+const LOOT_SHOP: [(u32, u32); 7] = [
+    (18, 3), // Cloth
+    (19, 3), // Nails
+    (11, 1), // Rope
+    (12, 1), // Lantern
+    (10, 2), // Lockpick
+    (16, 2), // Bandage
+    (3, 3),  // Apple
+];
+const LOOT_TAVERN: [(u32, u32); 6] = [
+    (4, 3), // Ale
+    (5, 2), // Wine
+    (6, 3), // Water
+    (1, 2), // Bread
+    (2, 2), // Cheese
+    (7, 2), // Candle
+];
+const LOOT_TEMPLE: [(u32, u32); 6] = [
+    (7, 3),  // Candle
+    (8, 2),  // Holy Water
+    (9, 1),  // Prayer Beads
+    (16, 2), // Bandage
+    (17, 1), // Healing Potion
+    (6, 2),  // Water
+];
+
+// Consts for fallback loot tables per container type (item ID, max quantity)
+const LOOT_BARREL: [(u32, u32); 2] = [(6, 3), (3, 3)];
+const LOOT_CRATE: [(u32, u32); 2] = [(19, 3), (18, 2)];
+const LOOT_CHEST: [(u32, u32); 2] = [(18, 2), (7, 2)];
+
+// Generate the contents of a container from the loot tables
+pub fn generate_contents(
+    container_type: &super::manager::ContainerType,
+    building_type: &super::manager::BuildingType,
+    rng_manager: &crate::core::rng::RngManager,
+) -> Vec<InventorySlot> {
+    // Items from the building's table that fit in this type of container
+    let mut table: Vec<(u32, u32)> = building_table(building_type)
+        .iter()
+        .filter(|(item_id, _)| {
+            Item::from_id(*item_id)
+                .map(|item| container_holds(container_type, &item.category))
+                .unwrap_or(false)
+        })
+        .copied()
+        .collect();
+
+    if table.is_empty() {
+        table = container_table(container_type).to_vec();
+    }
+
+    // Number of rolls on the table
+    let rolls = match container_type {
+        super::manager::ContainerType::Barrel => rng_manager.roll_dice(2),
+        super::manager::ContainerType::Crate => rng_manager.roll_dice(3),
+        super::manager::ContainerType::Chest => rng_manager.roll_dice(3) + 1,
+    };
+
+    let mut contents: Vec<InventorySlot> = Vec::new();
+
+    for _ in 0..rolls {
+        let (item_id, max_quantity) = table[rng_manager.pick_index(table.len())];
+
+        let item = match Item::from_id(item_id) {
+            Some(item) => item,
+            None => {
+                log::error!("Failed to find item for item ID: {}", item_id);
+                continue;
+            }
+        };
+
+        let quantity = if item.stackable {
+            rng_manager.roll_dice(max_quantity)
+        } else {
+            1
+        };
+
+        if item.stackable {
+            if let Some(slot) = contents.iter_mut().find(|slot| slot.item.id == item.id) {
+                slot.quantity += quantity;
+                continue;
+            }
+        }
+
+        contents.push(InventorySlot { item, quantity });
+    }
+
+    contents
+}
+
+// Get the loot table for a building type
+fn building_table(building_type: &super::manager::BuildingType) -> &'static [(u32, u32)] {
+    match building_type {
+        super::manager::BuildingType::Residence => &LOOT_RESIDENCE,
+        super::manager::BuildingType::Shop => &LOOT_SHOP,
+        super::manager::BuildingType::Tavern => &LOOT_TAVERN,
+        super::manager::BuildingType::Temple => &LOOT_TEMPLE,
+    }
+}
+
+// Get the fallback loot table for a container type
+fn container_table(container_type: &super::manager::ContainerType) -> &'static [(u32, u32)] {
+    match container_type {
+        super::manager::ContainerType::Barrel => &LOOT_BARREL,
+        super::manager::ContainerType::Crate => &LOOT_CRATE,
+        super::manager::ContainerType::Chest => &LOOT_CHEST,
+    }
+}
+
+// Check whether a type of container would hold a category of item
+// Barrels hold food and drink, crates hold bulk goods, chests hold anything
+fn container_holds(
+    container_type: &super::manager::ContainerType,
+    category: &ItemCategory,
+) -> bool {
+    match container_type {
+        super::manager::ContainerType::Barrel => {
+            matches!(category, ItemCategory::Food | ItemCategory::Drink)
+        }
+        super::manager::ContainerType::Crate => matches!(
+            category,
+            ItemCategory::Food
+                | ItemCategory::Drink
+                | ItemCategory::Tool
+                | ItemCategory::Material
+                | ItemCategory::Religious
+        ),
+        super::manager::ContainerType::Chest => true,
+    }
+}
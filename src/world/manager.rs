@@ -1,15 +1,30 @@
-use petgraph::algo::dijkstra;
+use petgraph::algo::{astar, dijkstra};
 use petgraph::graph::NodeIndex;
+use petgraph::visit::EdgeRef;
 use petgraph::{Graph, Undirected};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::PathBuf;
 
+// Const for the standing needed to enter the private rooms of a residence
+const PRIVATE_ROOM_STANDING: i32 = 3;
+
+// Const for the standing lost per level of hunger or fatigue, nobody haggles well on an empty
+// stomach
+const NEED_STANDING_PENALTY: i32 = 2;
+
+// Const for the standing gained per rank of haggling
+const HAGGLING_STANDING: i32 = 2;
+
 // Struct for World Manager
 pub struct WorldManager {
     pub player: Option<crate::entities::player::Player>,
     pub world: Option<World>,
+    pub container_contents: HashMap<u32, Vec<crate::entities::inventory::InventorySlot>>,
+    pub unlocked_containers: HashSet<u32>,
+    pub shop_stock: HashMap<u32, super::shop::ShopStock>,
+    pub quest_boards: HashMap<u32, super::quest::QuestBoard>,
     world_graph: Option<Graph<String, JourneyInfo, Undirected>>,
     node_indices: HashMap<String, NodeIndex>,
 }
@@ -21,6 +36,10 @@ impl WorldManager {
         Self {
             player: None,
             world: None,
+            container_contents: HashMap::new(),
+            unlocked_containers: HashSet::new(),
+            shop_stock: HashMap::new(),
+            quest_boards: HashMap::new(),
             world_graph: None,
             node_indices: HashMap::new(),
         }
@@ -30,6 +49,10 @@ impl WorldManager {
     pub fn clear(&mut self) {
         self.player = None;
         self.world = None;
+        self.container_contents = HashMap::new();
+        self.unlocked_containers = HashSet::new();
+        self.shop_stock = HashMap::new();
+        self.quest_boards = HashMap::new();
         self.world_graph = None;
         self.node_indices = HashMap::new();
     }
@@ -38,11 +61,23 @@ impl WorldManager {
     pub fn load_world(&mut self) -> Result<(), Box<dyn std::error::Error>> {
         let world_path = PathBuf::from("assets").join("world.json");
         let world_data = fs::read_to_string(world_path)?;
-        let world: World = serde_json::from_str(&world_data)?;
-        self.world = Some(world);
 
         let world_graph_path = PathBuf::from("assets").join("world.dot");
         let world_graph_data = fs::read_to_string(world_graph_path)?;
+
+        self.build_world(&world_data, &world_graph_data)
+    }
+
+    // Build the world and the roads between towns from world JSON and DOT data
+    fn build_world(
+        &mut self,
+        world_data: &str,
+        world_graph_data: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut world: World = serde_json::from_str(world_data)?;
+        assign_locks(&mut world);
+        self.world = Some(world);
+
         self.world_graph = Some(Graph::new_undirected());
 
         match self.world_graph.as_mut() {
@@ -72,6 +107,11 @@ impl WorldManager {
 
     // Get travel time between towns
     pub fn get_travel_time(&self, origin: &String, destination: &String) -> u32 {
+        self.get_travel_distance(origin, destination) * 10 // 1 mile = 10 ticks
+    }
+
+    // Get travel distance between towns in miles
+    pub fn get_travel_distance(&self, origin: &String, destination: &String) -> u32 {
         if let (Some(&origin_unwrapped), Some(&destination_unwrapped)) = (
             self.node_indices.get(origin),
             self.node_indices.get(destination),
@@ -81,11 +121,27 @@ impl WorldManager {
                     e.weight().distance
                 });
 
-                if let Some(cost) = path.get(&destination_unwrapped) {
-                    cost * 10 // 1 mile = 10 ticks
-                } else {
-                    0
-                }
+                path.get(&destination_unwrapped).copied().unwrap_or(0)
+            } else {
+                0
+            }
+        } else {
+            0
+        }
+    }
+
+    // Get travel cost between towns
+    pub fn get_travel_cost(&self, origin: &String, destination: &String) -> u32 {
+        if let (Some(&origin_unwrapped), Some(&destination_unwrapped)) = (
+            self.node_indices.get(origin),
+            self.node_indices.get(destination),
+        ) {
+            if let Some(graph) = self.world_graph.as_ref() {
+                let path = dijkstra(graph, origin_unwrapped, Some(destination_unwrapped), |e| {
+                    e.weight().cost
+                });
+
+                path.get(&destination_unwrapped).copied().unwrap_or(0)
             } else {
                 0
             }
@@ -93,6 +149,333 @@ impl WorldManager {
             0
         }
     }
+
+    // Get the shortest route between towns, returns the towns along the way and the distance
+    pub fn get_route(&self, origin: &String, destination: &String) -> Option<(Vec<String>, u32)> {
+        let origin_index = *self.node_indices.get(origin)?;
+        let destination_index = *self.node_indices.get(destination)?;
+        let graph = self.world_graph.as_ref()?;
+
+        let (distance, path) = astar(
+            graph,
+            origin_index,
+            |node| node == destination_index,
+            |e| e.weight().distance,
+            |_| 0,
+        )?;
+
+        let towns = path
+            .iter()
+            .filter_map(|node| graph.node_weight(*node).cloned())
+            .collect();
+
+        Some((towns, distance))
+    }
+
+    // Get the roads out of a town (destination, miles), sorted by destination
+    pub fn get_roads(&self, origin: &String) -> Vec<(String, u32)> {
+        let (origin_index, graph) = match (self.node_indices.get(origin), self.world_graph.as_ref())
+        {
+            (Some(&origin_index), Some(graph)) => (origin_index, graph),
+            _ => return Vec::new(),
+        };
+
+        let mut roads: Vec<(String, u32)> = graph
+            .edges(origin_index)
+            .filter_map(|edge| {
+                let other = if edge.source() == origin_index {
+                    edge.target()
+                } else {
+                    edge.source()
+                };
+
+                graph
+                    .node_weight(other)
+                    .map(|name| (name.clone(), edge.weight().distance))
+            })
+            .collect();
+        roads.sort();
+
+        roads
+    }
+
+    // Percentage of a town's buildings and rooms the player has been inside
+    pub fn town_exploration(&self, town_id: u32) -> u32 {
+        let (player, town) = match (
+            self.player.as_ref(),
+            self.world
+                .as_ref()
+                .and_then(|world| world.towns.get(&town_id)),
+        ) {
+            (Some(player), Some(town)) => (player, town),
+            _ => return 0,
+        };
+
+        let mut places = 0;
+        let mut visited = 0;
+
+        for building in &town.buildings {
+            places += 1 + building.rooms.len();
+            visited += player.visited_buildings.contains(&building.id) as usize;
+            visited += building
+                .rooms
+                .iter()
+                .filter(|room| player.visited_rooms.contains(&room.id))
+                .count();
+        }
+
+        percentage(visited, places)
+    }
+
+    // Percentage of the world's towns, buildings and rooms the player has visited
+    pub fn world_exploration(&self) -> u32 {
+        let (player, world) = match (self.player.as_ref(), self.world.as_ref()) {
+            (Some(player), Some(world)) => (player, world),
+            _ => return 0,
+        };
+
+        let places = world.towns.len() + world.buildings.len() + world.rooms.len();
+        let visited = player.visited_towns.len()
+            + player.visited_buildings.len()
+            + player.visited_rooms.len();
+
+        percentage(visited, places)
+    }
+
+    // Search NPCs by name, race or town, every word in the query has to match, sorted by name
+    pub fn search_npcs(&self, query: &str) -> Vec<&Npc> {
+        let world = match self.world.as_ref() {
+            Some(world) => world,
+            None => return Vec::new(),
+        };
+
+        let terms: Vec<String> = query
+            .split_whitespace()
+            .map(|term| term.to_lowercase())
+            .collect();
+
+        let mut npcs: Vec<&Npc> = world
+            .npcs
+            .values()
+            .filter(|npc| {
+                let town_name = world
+                    .towns
+                    .get(&npc.town_id)
+                    .map(|town| town.name.to_lowercase())
+                    .unwrap_or_default();
+                let name = npc.name.to_lowercase();
+                let race = format!("{:?}", npc.race).to_lowercase();
+
+                terms.iter().all(|term| {
+                    name.contains(term.as_str())
+                        || race.contains(term.as_str())
+                        || town_name.contains(term.as_str())
+                })
+            })
+            .collect();
+        npcs.sort_by(|a, b| a.name.cmp(&b.name).then(a.id.cmp(&b.id)));
+
+        npcs
+    }
+
+    // Open a container, generating its contents from the loot tables the first time
+    pub fn open_container(
+        &mut self,
+        container_id: u32,
+        rng_manager: &crate::core::rng::RngManager,
+    ) -> Option<&mut Vec<crate::entities::inventory::InventorySlot>> {
+        if !self.container_contents.contains_key(&container_id) {
+            let world = self.world.as_ref()?;
+            let container = world.containers.get(&container_id)?;
+            let building = world.buildings.get(&container.building_id)?;
+
+            let contents = super::loot::generate_contents(
+                &container.container_type,
+                &building.building_type,
+                rng_manager,
+            );
+
+            self.container_contents.insert(container_id, contents);
+        }
+
+        self.container_contents.get_mut(&container_id)
+    }
+
+    // Restock a shop if it hasn't been stocked yet today
+    pub fn restock_shop(
+        &mut self,
+        building_id: u32,
+        day: u32,
+        rng_manager: &crate::core::rng::RngManager,
+    ) {
+        let needs_restock = self
+            .shop_stock
+            .get(&building_id)
+            .map(|stock| stock.day != day)
+            .unwrap_or(true);
+
+        if needs_restock {
+            self.shop_stock.insert(
+                building_id,
+                super::shop::ShopStock::generate(day, rng_manager),
+            );
+        }
+    }
+
+    // Pin fresh quests to a tavern notice board if it hasn't been done yet today
+    pub fn refresh_quest_board(
+        &mut self,
+        building_id: u32,
+        day: u32,
+        rng_manager: &crate::core::rng::RngManager,
+    ) {
+        let needs_refresh = self
+            .quest_boards
+            .get(&building_id)
+            .map(|board| board.day != day)
+            .unwrap_or(true);
+
+        let town_id = self
+            .world
+            .as_ref()
+            .and_then(|world| world.buildings.get(&building_id))
+            .map(|building| building.town_id);
+
+        if let (true, Some(town_id)) = (needs_refresh, town_id) {
+            let board = super::quest::QuestBoard::generate(self, town_id, day, rng_manager);
+            self.quest_boards.insert(building_id, board);
+        }
+    }
+
+    // Get the NPC who runs a building, the first NPC found in its rooms
+    pub fn proprietor(&self, building_id: u32) -> Option<&Npc> {
+        self.world
+            .as_ref()?
+            .buildings
+            .get(&building_id)?
+            .rooms
+            .iter()
+            .flat_map(|room| room.npcs.iter())
+            .next()
+    }
+
+    // The player's standing in the current town plus how the proprietor of the current building
+    // feels about them, swayed by charisma and haggling and worn down by hunger and fatigue
+    pub fn standing(&self) -> i32 {
+        let player = match self.player.as_ref() {
+            Some(player) => player,
+            None => return 0,
+        };
+
+        let disposition = player
+            .current_building_id
+            .and_then(|building_id| self.proprietor(building_id))
+            .map(|npc| player.disposition(npc.id))
+            .unwrap_or(0);
+
+        player.reputation(player.current_town_id)
+            + disposition
+            + player.attributes.charm()
+            + player.progression.haggling as i32 * HAGGLING_STANDING
+            - player.condition.penalty() as i32 * NEED_STANDING_PENALTY
+    }
+
+    // Build up the player's hunger and fatigue for the time that has passed
+    pub fn update_needs(&mut self, time: Option<super::time::GameTime>) {
+        if let (Some(player), Some(time)) = (self.player.as_mut(), time) {
+            let stamina = player.attributes.stamina;
+            player.condition.pass_time(time.total_ticks(), stamina);
+        }
+    }
+
+    // Whether the player is welcome in a room, the rooms past the first in a residence are private
+    // and only open to those liked by someone who lives there or respected in town
+    pub fn can_enter_room(&self, building_id: u32, room_id: u32) -> bool {
+        let (player, building) = match (
+            self.player.as_ref(),
+            self.world
+                .as_ref()
+                .and_then(|world| world.buildings.get(&building_id)),
+        ) {
+            (Some(player), Some(building)) => (player, building),
+            _ => return false,
+        };
+
+        if building.building_type != BuildingType::Residence
+            || building.rooms.first().map(|room| room.id) == Some(room_id)
+        {
+            return true;
+        }
+
+        player.reputation(building.town_id) >= PRIVATE_ROOM_STANDING
+            || building
+                .rooms
+                .iter()
+                .flat_map(|room| room.npcs.iter())
+                .any(|npc| player.disposition(npc.id) >= PRIVATE_ROOM_STANDING)
+    }
+
+    // Get the NPCs in a room during a phase of the day, sorted by ID
+    pub fn npcs_in_room(&self, room_id: u32, phase: &super::time::Phase) -> Vec<&Npc> {
+        let world = match self.world.as_ref() {
+            Some(world) => world,
+            None => return Vec::new(),
+        };
+
+        let mut npcs: Vec<&Npc> = world
+            .npcs
+            .values()
+            .filter(|npc| super::schedule::npc_location(world, npc, phase).1 == room_id)
+            .collect();
+        npcs.sort_by_key(|npc| npc.id);
+
+        npcs
+    }
+
+    // Check whether a container is locked
+    pub fn is_container_locked(&self, container_id: u32) -> bool {
+        if self.unlocked_containers.contains(&container_id) {
+            return false;
+        }
+
+        self.world
+            .as_ref()
+            .and_then(|world| world.containers.get(&container_id))
+            .map(|container| container.locked)
+            .unwrap_or(false)
+    }
+
+    // Check whether a container has been opened and emptied
+    pub fn is_container_empty(&self, container_id: u32) -> bool {
+        self.container_contents
+            .get(&container_id)
+            .map(|contents| contents.is_empty())
+            .unwrap_or(false)
+    }
+}
+
+// Locks the chests in residences, difficulty comes from the container ID so it's the same every game
+fn assign_locks(world: &mut World) {
+    for container in world.containers.values_mut() {
+        let in_residence = world
+            .buildings
+            .get(&container.building_id)
+            .map(|building| building.building_type == BuildingType::Residence)
+            .unwrap_or(false);
+
+        if matches!(container.container_type, ContainerType::Chest) && in_residence {
+            container.locked = true;
+        }
+
+        if container.locked && container.lock_difficulty == 0 {
+            container.lock_difficulty = 1 + container.id % 5;
+        }
+    }
+}
+
+// Whole percentage of a count out of a total, 0 if there's nothing to count
+fn percentage(count: usize, total: usize) -> u32 {
+    (count * 100).checked_div(total).unwrap_or(0) as u32
 }
 
 // Parses an edge line from the DOT file and extracts (town1, town2, label).
@@ -151,8 +534,8 @@ pub struct World {
     pub towns: HashMap<u32, Town>,
     pub buildings: HashMap<u32, Building>,
     pub rooms: HashMap<u32, Room>,
-    npcs: HashMap<u32, Npc>,
-    containers: HashMap<u32, Container>,
+    pub npcs: HashMap<u32, Npc>,
+    pub containers: HashMap<u32, Container>,
 }
 
 // Struct for representing a town
@@ -168,23 +551,36 @@ pub struct Town {
 // Struct for representing a building
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Building {
-    id: u32,
+    pub id: u32,
     pub name: String,
-    building_type: BuildingType,
-    town_id: u32,
+    pub building_type: BuildingType,
+    pub town_id: u32,
     coords: (u32, u32),
     pub rooms: Vec<Room>,
 }
 
 // Enum for building types
-#[derive(Serialize, Deserialize, Debug, Clone)]
-enum BuildingType {
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum BuildingType {
     Residence,
     Shop,
     Tavern,
     Temple,
 }
 
+// Functions for building types
+impl BuildingType {
+    // The role of an NPC who belongs to this type of building
+    pub fn npc_role(&self) -> &str {
+        match self {
+            BuildingType::Residence => "Resident",
+            BuildingType::Shop => "Shopkeeper",
+            BuildingType::Tavern => "Innkeeper",
+            BuildingType::Temple => "Priest",
+        }
+    }
+}
+
 // Struct for representing a room
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Room {
@@ -198,17 +594,17 @@ pub struct Room {
 // Struct for representing an NPC
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Npc {
-    id: u32,
+    pub id: u32,
     pub name: String,
     pub sex: NpcSex,
     pub race: NpcRace,
-    town_id: u32,
-    building_id: u32,
-    room_id: Option<u32>,
+    pub town_id: u32,
+    pub building_id: u32,
+    pub room_id: Option<u32>,
 }
 
 // Enum for NPC sex
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub enum NpcSex {
     Male,
     Female,
@@ -216,7 +612,7 @@ pub enum NpcSex {
 }
 
 // Enum for NPC race
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub enum NpcRace {
     Human,
     Elf,
@@ -225,11 +621,29 @@ pub enum NpcRace {
 // Struct for representing a container
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Container {
-    id: u32,
+    pub id: u32,
     pub container_type: ContainerType,
     town_id: u32,
-    building_id: u32,
-    room_id: u32,
+    pub building_id: u32,
+    pub room_id: u32,
+    #[serde(default)]
+    pub locked: bool,
+    #[serde(default)]
+    pub lock_difficulty: u32,
+}
+
+// Functions for container
+impl Container {
+    // Describe how hard the lock is to pick
+    pub fn lock_description(&self) -> &str {
+        match self.lock_difficulty {
+            0 | 1 => "Simple",
+            2 => "Easy",
+            3 => "Tricky",
+            4 => "Hard",
+            _ => "Masterwork",
+        }
+    }
 }
 
 // Enum for container types
@@ -239,3 +653,265 @@ pub enum ContainerType {
     Crate,
     Chest,
 }
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    // A small world to test against, Ashford and Brook are joined by a road and Cove is cut off
+    // Each building is (ID, name, type, rooms), each room is (ID, NPCs, containers)
+    pub fn world_manager() -> WorldManager {
+        let towns = [
+            (
+                1,
+                "Ashford",
+                vec![
+                    (
+                        10,
+                        "Ash House",
+                        "Residence",
+                        vec![
+                            (100, vec![(2, "Ada Reed", "Human")], vec![(500, "Chest")]),
+                            (101, vec![(3, "Cole Ash", "Elf")], vec![]),
+                        ],
+                    ),
+                    (
+                        11,
+                        "Ash Goods",
+                        "Shop",
+                        vec![(110, vec![(4, "Dara Finch", "Human")], vec![(501, "Barrel")])],
+                    ),
+                    (
+                        12,
+                        "The Grey Goose",
+                        "Tavern",
+                        vec![(120, vec![(5, "Edric Gale", "Human")], vec![])],
+                    ),
+                    (
+                        13,
+                        "Temple of Ash",
+                        "Temple",
+                        vec![(130, vec![(7, "Faye Holm", "Elf")], vec![])],
+                    ),
+                ],
+            ),
+            (
+                2,
+                "Brook",
+                vec![
+                    (
+                        20,
+                        "Brook House",
+                        "Residence",
+                        vec![(200, vec![(13, "Jon Marsh", "Human")], vec![(511, "Chest")])],
+                    ),
+                    (
+                        21,
+                        "Brook Market",
+                        "Shop",
+                        vec![(210, vec![(8, "Gwen Reed", "Elf")], vec![(510, "Barrel")])],
+                    ),
+                    (
+                        22,
+                        "The Mill",
+                        "Tavern",
+                        vec![(220, vec![(9, "Hal Brook", "Human")], vec![])],
+                    ),
+                    (
+                        23,
+                        "Temple of Brook",
+                        "Temple",
+                        vec![(230, vec![(11, "Ivo Stone", "Human")], vec![])],
+                    ),
+                ],
+            ),
+            (
+                3,
+                "Cove",
+                vec![(
+                    30,
+                    "Cove Stores",
+                    "Shop",
+                    vec![(300, vec![(15, "Kit Shore", "Elf")], vec![(520, "Crate")])],
+                )],
+            ),
+        ];
+
+        let mut world = serde_json::json!({
+            "towns": {}, "buildings": {}, "rooms": {}, "npcs": {}, "containers": {}
+        });
+
+        for (town_id, town_name, buildings) in towns {
+            let mut town_buildings = Vec::new();
+
+            for (building_id, building_name, building_type, rooms) in buildings {
+                let mut building_rooms = Vec::new();
+
+                for (room_id, npcs, containers) in rooms {
+                    let npcs: Vec<_> = npcs
+                        .into_iter()
+                        .map(|(id, name, race)| {
+                            serde_json::json!({
+                                "id": id, "name": name, "sex": "Unisex", "race": race,
+                                "town_id": town_id, "building_id": building_id, "room_id": room_id
+                            })
+                        })
+                        .collect();
+                    let containers: Vec<_> = containers
+                        .into_iter()
+                        .map(|(id, container_type)| {
+                            serde_json::json!({
+                                "id": id, "container_type": container_type, "town_id": town_id,
+                                "building_id": building_id, "room_id": room_id
+                            })
+                        })
+                        .collect();
+
+                    for npc in &npcs {
+                        world["npcs"][npc["id"].to_string()] = npc.clone();
+                    }
+                    for container in &containers {
+                        world["containers"][container["id"].to_string()] = container.clone();
+                    }
+
+                    let room = serde_json::json!({
+                        "id": room_id, "town_id": town_id, "building_id": building_id,
+                        "npcs": npcs, "containers": containers
+                    });
+                    world["rooms"][room_id.to_string()] = room.clone();
+                    building_rooms.push(room);
+                }
+
+                let building = serde_json::json!({
+                    "id": building_id, "name": building_name, "building_type": building_type,
+                    "town_id": town_id, "coords": [0, 0], "rooms": building_rooms
+                });
+                world["buildings"][building_id.to_string()] = building.clone();
+                town_buildings.push(building);
+            }
+
+            world["towns"][town_id.to_string()] = serde_json::json!({
+                "id": town_id, "name": town_name, "coords": [0, 0],
+                "number_of_buildings": town_buildings.len(), "buildings": town_buildings
+            });
+        }
+
+        let world_graph = r#"graph Towns {
+    "Ashford" -- "Brook" [label="12 m / 60 gold", len=1];
+}"#;
+
+        let mut world_manager = WorldManager::new();
+        world_manager
+            .build_world(&world.to_string(), world_graph)
+            .unwrap();
+        world_manager
+    }
+
+    // The test world with the player standing in a building in Ashford
+    fn world_manager_in(building_id: Option<u32>, room_id: Option<u32>) -> WorldManager {
+        let mut world_manager = world_manager();
+        world_manager.player = Some(crate::entities::player::Player::new(
+            1,
+            "Bob".into(),
+            1,
+            building_id,
+            room_id,
+        ));
+        world_manager
+    }
+
+    #[test]
+    fn standing_adds_town_reputation_to_how_the_proprietor_feels() {
+        let mut world_manager = world_manager_in(Some(11), Some(110));
+
+        if let Some(player) = world_manager.player.as_mut() {
+            player.change_reputation(1, 3);
+            player.change_disposition(4, 2);
+            // Feelings about someone who doesn't run the shop don't count
+            player.change_disposition(2, 5);
+            // Nor does standing in another town
+            player.change_reputation(2, -8);
+        }
+        assert_eq!(world_manager.standing(), 5);
+
+        // Outside there's no proprietor, just the town
+        if let Some(player) = world_manager.player.as_mut() {
+            player.current_building_id = None;
+        }
+        assert_eq!(world_manager.standing(), 3);
+    }
+
+    #[test]
+    fn private_rooms_need_standing_with_the_town_or_a_resident() {
+        let mut world_manager = world_manager_in(Some(10), Some(100));
+
+        // The first room of a residence and every room elsewhere are open to all
+        assert!(world_manager.can_enter_room(10, 100));
+        assert!(world_manager.can_enter_room(11, 110));
+        assert!(!world_manager.can_enter_room(10, 101));
+
+        if let Some(player) = world_manager.player.as_mut() {
+            player.change_disposition(2, PRIVATE_ROOM_STANDING);
+        }
+        assert!(world_manager.can_enter_room(10, 101));
+
+        let mut world_manager = world_manager_in(Some(10), Some(100));
+        if let Some(player) = world_manager.player.as_mut() {
+            player.change_reputation(1, PRIVATE_ROOM_STANDING);
+        }
+        assert!(world_manager.can_enter_room(10, 101));
+    }
+
+    #[test]
+    fn exploration_counts_the_buildings_and_rooms_visited() {
+        let mut world_manager = world_manager_in(None, None);
+        assert_eq!(world_manager.town_exploration(1), 0);
+        assert_eq!(world_manager.world_exploration(), 0);
+
+        if let Some(player) = world_manager.player.as_mut() {
+            player.visited_towns.insert(1);
+            player.visited_buildings.insert(10);
+            player.visited_rooms.insert(100);
+        }
+
+        // Ashford has 4 buildings and 5 rooms, the world 3 towns, 9 buildings and 10 rooms
+        assert_eq!(world_manager.town_exploration(1), 22);
+        assert_eq!(world_manager.town_exploration(2), 0);
+        assert_eq!(world_manager.world_exploration(), 13);
+
+        if let Some(player) = world_manager.player.as_mut() {
+            player.visited_buildings.extend([11, 12, 13]);
+            player.visited_rooms.extend([101, 110, 120, 130]);
+        }
+        assert_eq!(world_manager.town_exploration(1), 100);
+
+        // Nothing to explore without a player
+        world_manager.player = None;
+        assert_eq!(world_manager.world_exploration(), 0);
+    }
+
+    // Names of the NPCs a search finds
+    fn search(world_manager: &WorldManager, query: &str) -> Vec<String> {
+        world_manager
+            .search_npcs(query)
+            .iter()
+            .map(|npc| npc.name.clone())
+            .collect()
+    }
+
+    #[test]
+    fn searching_npcs_matches_every_word_against_name_race_or_town() {
+        let world_manager = world_manager();
+
+        assert_eq!(search(&world_manager, "REED"), ["Ada Reed", "Gwen Reed"]);
+        assert_eq!(search(&world_manager, "reed brook"), ["Gwen Reed"]);
+        assert_eq!(
+            search(&world_manager, "elf ashford"),
+            ["Cole Ash", "Faye Holm"]
+        );
+        assert!(search(&world_manager, "reed cove").is_empty());
+
+        // Nothing to narrow by, so everyone is found
+        assert_eq!(search(&world_manager, "  ").len(), 10);
+    }
+}
@@ -1,3 +1,10 @@
+pub mod dialogue;
+pub mod loot;
 pub mod manager;
+pub mod quest;
+pub mod schedule;
+pub mod shop;
+pub mod tavern;
+pub mod temple;
 pub mod time;
 pub mod weather;
@@ -0,0 +1,1002 @@
+use super::manager::{BuildingType, World, WorldManager};
+use crate::entities::item::Item;
+use crate::entities::player::QuestStatus;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+// Const for how much finishing a quest warms the quest giver to the player
+const QUEST_DISPOSITION: i32 = 3;
+
+// Const for items that go missing in generated quests
+const GENERATED_QUEST_ITEMS: [u32; 5] = [
+    8,  // Holy Water
+    9,  // Prayer Beads
+    12, // Lantern
+    14, // Gold Ring
+    15, // Silver Necklace
+];
+
+// Consts for generated quest rewards, a base plus a gold for every few ticks of the round trip
+const GENERATED_REWARD_BASE: u32 = 10;
+const GENERATED_REWARD_TICKS_PER_GOLD: u32 = 10;
+
+// Consts for generated quest experience, a base plus a point for every few ticks of the round trip
+const GENERATED_EXPERIENCE_BASE: u32 = 20;
+const GENERATED_EXPERIENCE_TICKS_PER_POINT: u32 = 20;
+
+// Const for the number of quests pinned to a tavern notice board
+const BOARD_SIZE: usize = 3;
+
+// Struct for Quest Manager
+pub struct QuestManager {
+    quests: Option<Vec<QuestDefinition>>,
+}
+
+// Functions for Quest Manager
+impl QuestManager {
+    // Create a new Quest Manager
+    pub fn new() -> Self {
+        Self { quests: None }
+    }
+
+    // Load in quests JSON file
+    pub fn load_quests(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let quests_path = PathBuf::from("assets").join("quests.json");
+        let quests_data = fs::read_to_string(quests_path)?;
+        let quest_file: QuestFile = serde_json::from_str(&quests_data)?;
+        self.quests = Some(quest_file.quests);
+
+        Ok(())
+    }
+
+    // Start a quest given by an NPC, binding its objectives to places and people in the world
+    // Returns notes describing what happened
+    pub fn start(
+        &self,
+        quest_id: &str,
+        giver_id: u32,
+        world_manager: &mut WorldManager,
+        rng_manager: &crate::core::rng::RngManager,
+    ) -> Vec<String> {
+        let mut notes: Vec<String> = Vec::new();
+
+        let definition = match self
+            .quests
+            .as_ref()
+            .and_then(|quests| quests.iter().find(|quest| quest.id == quest_id))
+        {
+            Some(definition) => definition,
+            None => {
+                log::error!("Failed to find quest for quest ID: {}", quest_id);
+                return notes;
+            }
+        };
+
+        match world_manager.player.as_ref() {
+            Some(player) if player.quest_status(quest_id) == QuestStatus::NotStarted => {}
+            _ => return notes,
+        }
+
+        let objectives: Option<Vec<Objective>> = definition
+            .objectives
+            .iter()
+            .map(|objective| objective.bind(giver_id, world_manager, rng_manager))
+            .collect();
+
+        let objectives = match objectives {
+            Some(objectives) => objectives,
+            None => {
+                log::error!("Failed to bind objectives for quest ID: {}", quest_id);
+                return notes;
+            }
+        };
+
+        notes.extend(accept(
+            QuestLog {
+                quest_id: quest_id.to_string(),
+                title: definition.title.clone(),
+                description: definition.description.clone(),
+                giver_id,
+                objectives,
+                step: 0,
+                reward: definition.reward.clone(),
+            },
+            world_manager,
+            rng_manager,
+        ));
+
+        notes
+    }
+
+    // Move quests on when something happens in the world, returns notes describing what happened
+    pub fn handle(&self, mut event: QuestEvent, world_manager: &mut WorldManager) -> Vec<String> {
+        let mut notes: Vec<String> = Vec::new();
+
+        let (world, player) = match (world_manager.world.as_ref(), world_manager.player.as_mut()) {
+            (Some(world), Some(player)) => (world, player),
+            _ => return notes,
+        };
+
+        let mut completed: Vec<usize> = Vec::new();
+
+        for (index, log) in player.journal.iter_mut().enumerate() {
+            if log.is_complete() {
+                continue;
+            }
+
+            let advanced = match (log.objectives.get_mut(log.step), &mut event) {
+                (
+                    Some(Objective::VisitAllTemples { visited }),
+                    QuestEvent::EnterBuilding(building_id),
+                ) => {
+                    let is_temple = world
+                        .buildings
+                        .get(building_id)
+                        .map(|building| building.building_type == BuildingType::Temple)
+                        .unwrap_or(false);
+
+                    if is_temple && !visited.contains(building_id) {
+                        visited.push(*building_id);
+
+                        notes.push(format!(
+                            "{}: {}/{} temples visited.",
+                            log.title,
+                            visited.len(),
+                            temple_count(world)
+                        ));
+                    }
+
+                    // Temples visited before taking the quest count, so any temple can finish it
+                    is_temple && visited.len() >= temple_count(world)
+                }
+                (
+                    Some(Objective::Fetch {
+                        item_id,
+                        container_id,
+                    }),
+                    QuestEvent::EnterRoom(room_id),
+                ) => {
+                    if let Some(container) = world
+                        .containers
+                        .get(container_id)
+                        .filter(|container| container.room_id == *room_id)
+                    {
+                        notes.push(format!(
+                            "{}: The {} should be in the {:?} here.",
+                            log.title,
+                            item_name(*item_id),
+                            container.container_type
+                        ));
+                    }
+
+                    false
+                }
+                (
+                    Some(Objective::Fetch {
+                        item_id,
+                        container_id,
+                    }),
+                    QuestEvent::TakeItem(taken_from, taken),
+                ) if container_id == taken_from => {
+                    // Each item taken only counts towards one quest
+                    match taken
+                        .iter_mut()
+                        .find(|slot| slot.item.id == *item_id && slot.quantity > 0)
+                    {
+                        Some(slot) => {
+                            slot.quantity -= 1;
+                            notes.push(format!(
+                                "{}: You found the {}.",
+                                log.title,
+                                item_name(*item_id)
+                            ));
+                            true
+                        }
+                        None => false,
+                    }
+                }
+                (
+                    Some(Objective::Deliver {
+                        item_id,
+                        quantity,
+                        npc_id,
+                    }),
+                    QuestEvent::Talk(talked_to),
+                ) if npc_id == talked_to => {
+                    if player.inventory.count(*item_id) >= *quantity {
+                        player.inventory.remove_by_id(*item_id, *quantity);
+                        notes.push(format!(
+                            "{}: You hand over {} {}.",
+                            log.title,
+                            quantity,
+                            item_name(*item_id)
+                        ));
+                        true
+                    } else {
+                        notes.push(format!(
+                            "{}: They're expecting {} {}.",
+                            log.title,
+                            quantity,
+                            item_name(*item_id)
+                        ));
+                        false
+                    }
+                }
+                (Some(Objective::Message { npc_id }), QuestEvent::Talk(talked_to))
+                    if npc_id == talked_to =>
+                {
+                    notes.push(format!("{}: You deliver the message.", log.title));
+                    true
+                }
+                _ => false,
+            };
+
+            if advanced {
+                log.step += 1;
+
+                if log.is_complete() {
+                    completed.push(index);
+                }
+            }
+        }
+
+        // Hand out rewards for anything finished
+        for index in completed {
+            let (title, giver_id, reward) = match player.journal.get(index) {
+                Some(log) => (log.title.clone(), log.giver_id, log.reward.clone()),
+                None => continue,
+            };
+
+            player.gold += reward.gold;
+
+            for reward_item in &reward.items {
+                match Item::from_id(reward_item.item_id) {
+                    Some(item) => {
+                        if let Err(e) = player.inventory.add(item, reward_item.quantity) {
+                            notes.push(e.to_string());
+                        }
+                    }
+                    None => log::error!("Failed to find item for item ID: {}", reward_item.item_id),
+                }
+            }
+
+            if let Some(giver) = world.npcs.get(&giver_id) {
+                player.change_reputation(giver.town_id, reward.reputation);
+                player.change_disposition(giver.id, QUEST_DISPOSITION);
+            }
+
+            notes.push(format!(
+                "Quest complete: {}! You receive {}.",
+                title,
+                reward.describe()
+            ));
+
+            if let Some(note) = player.gain_experience(reward.experience) {
+                notes.push(note);
+            }
+        }
+
+        notes
+    }
+}
+
+// Take on a quest, hiding anything the player has to fetch, returns notes describing what happened
+pub fn accept(
+    log: QuestLog,
+    world_manager: &mut WorldManager,
+    rng_manager: &crate::core::rng::RngManager,
+) -> Vec<String> {
+    match world_manager.player.as_ref() {
+        Some(player) if player.quest_status(&log.quest_id) == QuestStatus::NotStarted => {}
+        Some(_) => return vec!["You've already taken that on.".into()],
+        None => return Vec::new(),
+    }
+
+    for objective in &log.objectives {
+        if let Objective::Fetch {
+            item_id,
+            container_id,
+        } = objective
+        {
+            match (
+                Item::from_id(*item_id),
+                world_manager.open_container(*container_id, rng_manager),
+            ) {
+                (Some(item), Some(contents)) => {
+                    contents.push(crate::entities::inventory::InventorySlot { item, quantity: 1 });
+                }
+                _ => log::error!(
+                    "Failed to place item ID: {} in container ID: {}",
+                    item_id,
+                    container_id
+                ),
+            }
+        }
+    }
+
+    match world_manager.player.as_mut() {
+        Some(player) => {
+            let note = format!("New quest: {}.", log.title);
+
+            player.journal.push(log);
+
+            vec![note]
+        }
+        None => Vec::new(),
+    }
+}
+
+// Generate a quest from the world data, someone in one town wants something fetched from another
+// The giver is picked at random from the town if not given
+pub fn generate_quest(
+    world_manager: &WorldManager,
+    town_id: u32,
+    giver_id: Option<u32>,
+    day: u32,
+    rng_manager: &crate::core::rng::RngManager,
+) -> Option<QuestLog> {
+    let world = world_manager.world.as_ref()?;
+    let town = world.towns.get(&town_id)?;
+
+    let giver = match giver_id {
+        Some(giver_id) => world.npcs.get(&giver_id)?,
+        None => {
+            let mut npcs: Vec<&super::manager::Npc> = world
+                .npcs
+                .values()
+                .filter(|npc| npc.town_id == town_id)
+                .collect();
+            npcs.sort_by_key(|npc| npc.id);
+
+            if npcs.is_empty() {
+                return None;
+            }
+
+            npcs[rng_manager.pick_index(npcs.len())]
+        }
+    };
+
+    let container = pick_fetch_container(world_manager, town_id, rng_manager)?;
+    let building = world.buildings.get(&container.building_id)?;
+    let destination = world.towns.get(&building.town_id)?;
+    let (route, distance) = world_manager.get_route(&town.name, &destination.name)?;
+
+    let item_id = GENERATED_QUEST_ITEMS[rng_manager.pick_index(GENERATED_QUEST_ITEMS.len())];
+
+    // There and back again
+    let travel_time = world_manager.get_travel_time(&town.name, &destination.name) * 2;
+
+    Some(QuestLog {
+        quest_id: format!("generated_{}_{}_{}", day, giver.id, container.id),
+        title: format!("{} for {}", item_name(item_id), giver.name),
+        description: format!(
+            "{} wants the {} left in a {:?} at {}, {}. The road: {} ({} miles).",
+            giver.name,
+            item_name(item_id),
+            container.container_type,
+            building.name,
+            destination.name,
+            route.join(" -> "),
+            distance
+        ),
+        giver_id: giver.id,
+        objectives: vec![
+            Objective::Fetch {
+                item_id,
+                container_id: container.id,
+            },
+            Objective::Deliver {
+                item_id,
+                quantity: 1,
+                npc_id: giver.id,
+            },
+        ],
+        step: 0,
+        reward: Reward {
+            gold: GENERATED_REWARD_BASE + travel_time / GENERATED_REWARD_TICKS_PER_GOLD,
+            experience: GENERATED_EXPERIENCE_BASE
+                + travel_time / GENERATED_EXPERIENCE_TICKS_PER_POINT,
+            items: Vec::new(),
+            reputation: 1,
+        },
+    })
+}
+
+// Pick an unlocked container in a shop, tavern or temple in another town with a road to it
+fn pick_fetch_container<'a>(
+    world_manager: &'a WorldManager,
+    town_id: u32,
+    rng_manager: &crate::core::rng::RngManager,
+) -> Option<&'a super::manager::Container> {
+    let world = world_manager.world.as_ref()?;
+    let town = world.towns.get(&town_id)?;
+
+    let mut containers: Vec<&super::manager::Container> = world
+        .containers
+        .values()
+        .filter(|container| !container.locked)
+        .filter(|container| {
+            world
+                .buildings
+                .get(&container.building_id)
+                .and_then(|building| {
+                    world
+                        .towns
+                        .get(&building.town_id)
+                        .map(|destination| (building, destination))
+                })
+                .map(|(building, destination)| {
+                    destination.id != town_id
+                        && building.building_type != BuildingType::Residence
+                        && world_manager
+                            .get_route(&town.name, &destination.name)
+                            .is_some()
+                })
+                .unwrap_or(false)
+        })
+        .collect();
+    containers.sort_by_key(|container| container.id);
+
+    if containers.is_empty() {
+        return None;
+    }
+
+    Some(containers[rng_manager.pick_index(containers.len())])
+}
+
+// Struct for the quests pinned to a tavern notice board, generated for a given day
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestBoard {
+    pub day: u32,
+    pub quests: Vec<QuestLog>,
+}
+
+// Functions for quest boards
+impl QuestBoard {
+    // Generate a fresh board for a day, leaving off anything the player has already taken on
+    pub fn generate(
+        world_manager: &WorldManager,
+        town_id: u32,
+        day: u32,
+        rng_manager: &crate::core::rng::RngManager,
+    ) -> Self {
+        let mut quests: Vec<QuestLog> = Vec::new();
+
+        for _ in 0..BOARD_SIZE {
+            if let Some(log) = generate_quest(world_manager, town_id, None, day, rng_manager) {
+                let taken = world_manager
+                    .player
+                    .as_ref()
+                    .map(|player| player.quest_status(&log.quest_id) != QuestStatus::NotStarted)
+                    .unwrap_or(false);
+
+                if !taken && !quests.iter().any(|quest| quest.quest_id == log.quest_id) {
+                    quests.push(log);
+                }
+            }
+        }
+
+        Self { day, quests }
+    }
+}
+
+// Struct for the quests file
+#[derive(Debug, Deserialize)]
+struct QuestFile {
+    quests: Vec<QuestDefinition>,
+}
+
+// Struct for an authored quest
+#[derive(Debug, Deserialize)]
+struct QuestDefinition {
+    id: String,
+    title: String,
+    description: String,
+    objectives: Vec<ObjectiveDefinition>,
+    reward: Reward,
+}
+
+// Enum for objectives as authored, before they're bound to the world
+#[derive(Debug, Deserialize)]
+enum ObjectiveDefinition {
+    Fetch {
+        item_id: u32,
+    },
+    Deliver {
+        item_id: u32,
+        quantity: u32,
+        to: Recipient,
+    },
+    Message {
+        to: Recipient,
+    },
+    VisitAllTemples,
+}
+
+// Functions for objective definitions
+impl ObjectiveDefinition {
+    // Bind the objective to places and people in the world
+    fn bind(
+        &self,
+        giver_id: u32,
+        world_manager: &WorldManager,
+        rng_manager: &crate::core::rng::RngManager,
+    ) -> Option<Objective> {
+        let world = world_manager.world.as_ref()?;
+        let giver = world.npcs.get(&giver_id)?;
+
+        match self {
+            ObjectiveDefinition::Fetch { item_id } => Some(Objective::Fetch {
+                item_id: *item_id,
+                container_id: pick_fetch_container(world_manager, giver.town_id, rng_manager)?.id,
+            }),
+            ObjectiveDefinition::Deliver {
+                item_id,
+                quantity,
+                to,
+            } => Some(Objective::Deliver {
+                item_id: *item_id,
+                quantity: *quantity,
+                npc_id: to.pick(giver, world, rng_manager)?,
+            }),
+            ObjectiveDefinition::Message { to } => Some(Objective::Message {
+                npc_id: to.pick(giver, world, rng_manager)?,
+            }),
+            // Temples already visited count towards it
+            ObjectiveDefinition::VisitAllTemples => {
+                let mut visited: Vec<u32> = world_manager
+                    .player
+                    .as_ref()?
+                    .visited_buildings
+                    .iter()
+                    .copied()
+                    .filter(|building_id| {
+                        world
+                            .buildings
+                            .get(building_id)
+                            .is_some_and(|building| building.building_type == BuildingType::Temple)
+                    })
+                    .collect();
+                visited.sort();
+
+                Some(Objective::VisitAllTemples { visited })
+            }
+        }
+    }
+}
+
+// Enum for who an objective is for
+#[derive(Debug, Deserialize)]
+enum Recipient {
+    Giver,
+    Neighbour,
+    Stranger,
+}
+
+// Functions for recipients
+impl Recipient {
+    // Pick an NPC, neighbours live in the giver's town and strangers live elsewhere
+    fn pick(
+        &self,
+        giver: &super::manager::Npc,
+        world: &World,
+        rng_manager: &crate::core::rng::RngManager,
+    ) -> Option<u32> {
+        let mut npcs: Vec<u32> = match self {
+            Recipient::Giver => return Some(giver.id),
+            Recipient::Neighbour => world
+                .npcs
+                .values()
+                .filter(|npc| npc.town_id == giver.town_id && npc.id != giver.id)
+                .map(|npc| npc.id)
+                .collect(),
+            Recipient::Stranger => world
+                .npcs
+                .values()
+                .filter(|npc| npc.town_id != giver.town_id)
+                .map(|npc| npc.id)
+                .collect(),
+        };
+        npcs.sort();
+
+        if npcs.is_empty() {
+            None
+        } else {
+            Some(npcs[rng_manager.pick_index(npcs.len())])
+        }
+    }
+}
+
+// Struct for a quest reward
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Reward {
+    #[serde(default)]
+    pub gold: u32,
+    #[serde(default)]
+    pub experience: u32,
+    #[serde(default)]
+    pub items: Vec<RewardItem>,
+    #[serde(default)]
+    pub reputation: i32,
+}
+
+// Functions for quest rewards
+impl Reward {
+    // Describe the reward
+    pub fn describe(&self) -> String {
+        let mut parts: Vec<String> = Vec::new();
+
+        if self.gold > 0 {
+            parts.push(format!("{} gold", self.gold));
+        }
+
+        if self.experience > 0 {
+            parts.push(format!("{} XP", self.experience));
+        }
+
+        for reward_item in &self.items {
+            parts.push(format!(
+                "{} x{}",
+                item_name(reward_item.item_id),
+                reward_item.quantity
+            ));
+        }
+
+        if parts.is_empty() {
+            "their thanks".into()
+        } else {
+            parts.join(", ")
+        }
+    }
+}
+
+// Struct for an item given as a reward
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RewardItem {
+    pub item_id: u32,
+    pub quantity: u32,
+}
+
+// Struct for a quest the player has taken on
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestLog {
+    pub quest_id: String,
+    pub title: String,
+    pub description: String,
+    pub giver_id: u32,
+    pub objectives: Vec<Objective>,
+    pub step: usize,
+    pub reward: Reward,
+}
+
+// Functions for quest logs
+impl QuestLog {
+    // Check whether every objective is done
+    pub fn is_complete(&self) -> bool {
+        self.step >= self.objectives.len()
+    }
+}
+
+// Enum for objectives bound to the world
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Objective {
+    Fetch {
+        item_id: u32,
+        container_id: u32,
+    },
+    Deliver {
+        item_id: u32,
+        quantity: u32,
+        npc_id: u32,
+    },
+    Message {
+        npc_id: u32,
+    },
+    VisitAllTemples {
+        visited: Vec<u32>,
+    },
+}
+
+// Functions for objectives
+impl Objective {
+    // Describe what needs doing
+    pub fn describe(&self, world: &World) -> String {
+        match self {
+            Objective::Fetch {
+                item_id,
+                container_id,
+            } => match world.containers.get(container_id).and_then(|container| {
+                world
+                    .buildings
+                    .get(&container.building_id)
+                    .map(|building| (container, building))
+            }) {
+                Some((container, building)) => format!(
+                    "Find the {} in a {:?} at {}, {}.",
+                    item_name(*item_id),
+                    container.container_type,
+                    building.name,
+                    town_name(world, building.town_id)
+                ),
+                None => format!("Find the {}.", item_name(*item_id)),
+            },
+            Objective::Deliver {
+                item_id,
+                quantity,
+                npc_id,
+            } => format!(
+                "Take {} {} to {}.",
+                quantity,
+                item_name(*item_id),
+                npc_description(world, *npc_id)
+            ),
+            Objective::Message { npc_id } => {
+                format!("Deliver a message to {}.", npc_description(world, *npc_id))
+            }
+            Objective::VisitAllTemples { visited } => format!(
+                "Visit every temple in the land ({}/{}).",
+                visited.len(),
+                temple_count(world)
+            ),
+        }
+    }
+}
+
+// Enum for things that happen in the world that quests care about
+pub enum QuestEvent {
+    EnterBuilding(u32),
+    EnterRoom(u32),
+    // Container ID and the items taken from it
+    TakeItem(u32, Vec<crate::entities::inventory::InventorySlot>),
+    Talk(u32),
+}
+
+// Count the temples in the world
+fn temple_count(world: &World) -> usize {
+    world
+        .buildings
+        .values()
+        .filter(|building| building.building_type == BuildingType::Temple)
+        .count()
+}
+
+// Get the name of an item by ID
+fn item_name(item_id: u32) -> String {
+    Item::from_id(item_id)
+        .map(|item| item.name)
+        .unwrap_or_else(|| "Unknown Item".into())
+}
+
+// Get the name of a town by ID
+fn town_name(world: &World, town_id: u32) -> String {
+    world
+        .towns
+        .get(&town_id)
+        .map(|town| town.name.clone())
+        .unwrap_or_default()
+}
+
+// Describe an NPC by name and home
+fn npc_description(world: &World, npc_id: u32) -> String {
+    match world.npcs.get(&npc_id).and_then(|npc| {
+        world
+            .buildings
+            .get(&npc.building_id)
+            .map(|building| (npc, building))
+    }) {
+        Some((npc, building)) => format!(
+            "{} at {}, {}",
+            npc.name,
+            building.name,
+            town_name(world, building.town_id)
+        ),
+        None => "someone".into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::player::Player;
+
+    const QUESTS: &str = r#"{
+        "quests": [
+            {
+                "id": "lost_ring",
+                "title": "The Lost Ring",
+                "description": "Find the ring and bring it back.",
+                "objectives": [
+                    { "Fetch": { "item_id": 14 } },
+                    { "Deliver": { "item_id": 14, "quantity": 1, "to": "Giver" } }
+                ],
+                "reward": { "gold": 40, "experience": 60, "reputation": 2 }
+            },
+            {
+                "id": "temple_pilgrimage",
+                "title": "The Pilgrimage",
+                "description": "Visit every temple in the land.",
+                "objectives": ["VisitAllTemples"],
+                "reward": { "gold": 200, "items": [{ "item_id": 9, "quantity": 1 }] }
+            }
+        ]
+    }"#;
+
+    fn quest_manager() -> QuestManager {
+        QuestManager {
+            quests: Some(serde_json::from_str::<QuestFile>(QUESTS).unwrap().quests),
+        }
+    }
+
+    fn rng_manager() -> crate::core::rng::RngManager {
+        let mut rng_manager = crate::core::rng::RngManager::new(Some(7));
+        rng_manager.start_new();
+        rng_manager
+    }
+
+    // The test world with the player outside in Ashford
+    fn world_manager() -> WorldManager {
+        let mut world_manager = crate::world::manager::tests::world_manager();
+        world_manager.player = Some(Player::new(1, "Bob".into(), 1, None, None));
+        world_manager
+    }
+
+    fn player(world_manager: &WorldManager) -> &Player {
+        world_manager.player.as_ref().unwrap()
+    }
+
+    #[test]
+    fn fetch_quests_run_from_accepting_to_the_reward() {
+        let quest_manager = quest_manager();
+        let rng_manager = rng_manager();
+        let mut world_manager = world_manager();
+
+        // Brook's market barrel is the only container out of town that can be reached and opened
+        let notes = quest_manager.start("lost_ring", 4, &mut world_manager, &rng_manager);
+        assert_eq!(notes, ["New quest: The Lost Ring."]);
+        assert_eq!(
+            player(&world_manager).quest_status("lost_ring"),
+            QuestStatus::Active
+        );
+        assert!(matches!(
+            player(&world_manager).journal[0].objectives[0],
+            Objective::Fetch {
+                item_id: 14,
+                container_id: 510
+            }
+        ));
+        assert!(world_manager.container_contents[&510]
+            .iter()
+            .any(|slot| slot.item.id == 14));
+
+        // Taking it on twice does nothing
+        assert!(quest_manager
+            .start("lost_ring", 4, &mut world_manager, &rng_manager)
+            .is_empty());
+
+        let notes = quest_manager.handle(QuestEvent::EnterRoom(210), &mut world_manager);
+        assert_eq!(
+            notes,
+            ["The Lost Ring: The Gold Ring should be in the Barrel here."]
+        );
+
+        // Taking something else, or the ring from elsewhere, doesn't count
+        let ring = crate::entities::inventory::InventorySlot {
+            item: Item::from_id(14).unwrap(),
+            quantity: 1,
+        };
+        quest_manager.handle(
+            QuestEvent::TakeItem(501, vec![ring.clone()]),
+            &mut world_manager,
+        );
+        assert_eq!(player(&world_manager).journal[0].step, 0);
+
+        let notes = quest_manager.handle(
+            QuestEvent::TakeItem(510, vec![ring.clone()]),
+            &mut world_manager,
+        );
+        assert_eq!(notes, ["The Lost Ring: You found the Gold Ring."]);
+        assert_eq!(player(&world_manager).journal[0].step, 1);
+
+        // The ring has to be in hand to hand it over
+        let notes = quest_manager.handle(QuestEvent::Talk(4), &mut world_manager);
+        assert_eq!(notes, ["The Lost Ring: They're expecting 1 Gold Ring."]);
+
+        if let Some(player) = world_manager.player.as_mut() {
+            player.inventory.add(ring.item.clone(), 1).unwrap();
+        }
+        let notes = quest_manager.handle(QuestEvent::Talk(4), &mut world_manager);
+        assert_eq!(
+            notes,
+            [
+                "The Lost Ring: You hand over 1 Gold Ring.",
+                "Quest complete: The Lost Ring! You receive 40 gold, 60 XP."
+            ]
+        );
+
+        let player = player(&world_manager);
+        assert_eq!(player.quest_status("lost_ring"), QuestStatus::Completed);
+        assert_eq!(player.inventory.count(14), 0);
+        assert_eq!(player.gold, 40);
+        assert_eq!(player.progression.experience, 60);
+        assert_eq!(player.reputation(1), 2);
+        assert_eq!(player.disposition(4), QUEST_DISPOSITION);
+    }
+
+    #[test]
+    fn temples_visited_before_the_pilgrimage_count() {
+        let quest_manager = quest_manager();
+        let rng_manager = rng_manager();
+        let mut world_manager = world_manager();
+
+        if let Some(player) = world_manager.player.as_mut() {
+            player.visited_buildings.insert(13);
+        }
+        quest_manager.start("temple_pilgrimage", 7, &mut world_manager, &rng_manager);
+
+        // Somewhere that isn't a temple
+        assert!(quest_manager
+            .handle(QuestEvent::EnterBuilding(21), &mut world_manager)
+            .is_empty());
+
+        let notes = quest_manager.handle(QuestEvent::EnterBuilding(23), &mut world_manager);
+        assert_eq!(
+            notes,
+            [
+                "The Pilgrimage: 2/2 temples visited.",
+                "Quest complete: The Pilgrimage! You receive 200 gold, Prayer Beads x1."
+            ]
+        );
+        assert_eq!(player(&world_manager).inventory.count(9), 1);
+    }
+
+    #[test]
+    fn generated_quests_fetch_from_a_town_down_the_road() {
+        let world_manager = world_manager();
+
+        let log = generate_quest(&world_manager, 1, Some(4), 3, &rng_manager()).unwrap();
+        assert_eq!(log.quest_id, "generated_3_4_510");
+        assert!(matches!(
+            log.objectives.as_slice(),
+            [
+                Objective::Fetch {
+                    container_id: 510,
+                    ..
+                },
+                Objective::Deliver {
+                    quantity: 1,
+                    npc_id: 4,
+                    ..
+                }
+            ]
+        ));
+
+        // The reward grows with the round trip, 12 miles there and back
+        assert_eq!(log.reward.gold, 34);
+        assert_eq!(log.reward.experience, 32);
+
+        // Cove has no road to anywhere
+        assert!(generate_quest(&world_manager, 3, None, 3, &rng_manager()).is_none());
+    }
+
+    #[test]
+    fn quest_boards_leave_off_quests_already_taken() {
+        let mut world_manager = world_manager();
+
+        let board = QuestBoard::generate(&world_manager, 1, 3, &rng_manager());
+        assert!(!board.quests.is_empty());
+        assert!(board.quests.len() <= BOARD_SIZE);
+        assert!(board.quests.iter().all(|log| board
+            .quests
+            .iter()
+            .filter(|other| other.quest_id == log.quest_id)
+            .count()
+            == 1));
+
+        let taken = board.quests[0].quest_id.clone();
+        accept(board.quests[0].clone(), &mut world_manager, &rng_manager());
+
+        let board = QuestBoard::generate(&world_manager, 1, 3, &rng_manager());
+        assert!(board.quests.iter().all(|log| log.quest_id != taken));
+    }
+}
@@ -0,0 +1,98 @@
+use super::manager::{BuildingType, Npc, World};
+use super::time::Phase;
+
+// Work out where an NPC is during a phase of the day, returns (building ID, room ID)
+// An NPC's place in the world data is their home, schedules move them around from there
+pub fn npc_location(world: &World, npc: &Npc, phase: &Phase) -> (u32, u32) {
+    let home = (npc.building_id, npc.room_id.unwrap_or_default());
+
+    let home_type = match world.buildings.get(&npc.building_id) {
+        Some(building) => building.building_type.clone(),
+        None => return home,
+    };
+
+    let destination = match (home_type, phase) {
+        // Residents mostly stay home, some go shopping by day and to the tavern at dusk
+        (BuildingType::Residence, Phase::Day) if npc.id.is_multiple_of(3) => {
+            visit(world, npc, BuildingType::Shop)
+        }
+        (BuildingType::Residence, Phase::Dusk) if npc.id.is_multiple_of(2) => {
+            visit(world, npc, BuildingType::Tavern)
+        }
+        // Shopkeepers work by day, some stop at the tavern on the way home, and sleep elsewhere
+        (BuildingType::Shop, Phase::Dusk) if npc.id.is_multiple_of(2) => {
+            visit(world, npc, BuildingType::Tavern)
+        }
+        (BuildingType::Shop, Phase::Night) => visit(world, npc, BuildingType::Residence),
+        // Tavern staff work late and sleep through the morning
+        (BuildingType::Tavern, Phase::Dawn) => visit(world, npc, BuildingType::Residence),
+        // Priests never leave their temple
+        _ => None,
+    };
+
+    destination.unwrap_or(home)
+}
+
+// Pick a building of a type in the NPC's town for them to visit, returns (building ID, room ID)
+// The choice comes from the NPC's ID, so they always go to the same place
+fn visit(world: &World, npc: &Npc, building_type: BuildingType) -> Option<(u32, u32)> {
+    let town = world.towns.get(&npc.town_id)?;
+
+    let buildings: Vec<_> = town
+        .buildings
+        .iter()
+        .filter(|building| building.building_type == building_type)
+        .collect();
+
+    if buildings.is_empty() {
+        return None;
+    }
+
+    let building = buildings[npc.id as usize % buildings.len()];
+    let room = building.rooms.first()?;
+
+    Some((building.id, room.id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Where an NPC is during each phase, from dawn through to night
+    fn day_of(npc_id: u32) -> Vec<(u32, u32)> {
+        let world_manager = crate::world::manager::tests::world_manager();
+        let world = world_manager.world.as_ref().unwrap();
+        let npc = &world.npcs[&npc_id];
+
+        [Phase::Dawn, Phase::Day, Phase::Dusk, Phase::Night]
+            .iter()
+            .map(|phase| npc_location(world, npc, phase))
+            .collect()
+    }
+
+    #[test]
+    fn residents_go_shopping_or_to_the_tavern_by_their_id() {
+        // Every third resident shops by day
+        assert_eq!(day_of(3), [(10, 101), (11, 110), (10, 101), (10, 101)]);
+        // Every second resident goes to the tavern at dusk
+        assert_eq!(day_of(2), [(10, 100), (10, 100), (12, 120), (10, 100)]);
+    }
+
+    #[test]
+    fn shopkeepers_sleep_in_a_residence() {
+        assert_eq!(day_of(4), [(11, 110), (11, 110), (12, 120), (10, 100)]);
+
+        // Nowhere else to go in a town without a residence or tavern
+        assert_eq!(day_of(15), [(30, 300); 4]);
+    }
+
+    #[test]
+    fn tavern_staff_sleep_through_the_morning() {
+        assert_eq!(day_of(5), [(10, 100), (12, 120), (12, 120), (12, 120)]);
+    }
+
+    #[test]
+    fn priests_never_leave_their_temple() {
+        assert_eq!(day_of(7), [(13, 130); 4]);
+    }
+}
@@ -0,0 +1,111 @@
+use crate::entities::inventory::InventorySlot;
+use crate::entities::item::Item;
+use serde::{Deserialize, Serialize};
+
+// Const for shop stock table (item ID, max quantity)
+const SHOP_STOCK: [(u32, u32); 14] = [
+    (1, 6),  // Bread
+    (2, 4),  // Cheese
+    (3, 8),  // Apple
+    (4, 6),  // Ale
+    (6, 6),  // Water
+    (7, 8),  // Candle
+    (10, 4), // Lockpick
+    (11, 1), // Rope
+    (12, 1), // Lantern
+    (13, 1), // Dagger
+    (16, 4), // Bandage
+    (17, 2), // Healing Potion
+    (18, 5), // Cloth
+    (19, 8), // Nails
+];
+
+// Consts for stock size
+const MIN_STOCK_LINES: u32 = 5;
+const MAX_STOCK_LINES: u32 = 9;
+
+// Struct for a shop's stock, generated for a given day
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShopStock {
+    pub day: u32,
+    pub items: Vec<InventorySlot>,
+}
+
+// Functions for shop stock
+impl ShopStock {
+    // Generate fresh stock for a day
+    pub fn generate(day: u32, rng_manager: &crate::core::rng::RngManager) -> Self {
+        let lines =
+            MIN_STOCK_LINES - 1 + rng_manager.roll_dice(MAX_STOCK_LINES - MIN_STOCK_LINES + 1);
+        let mut items: Vec<InventorySlot> = Vec::new();
+
+        for _ in 0..lines {
+            let (item_id, max_quantity) = SHOP_STOCK[rng_manager.pick_index(SHOP_STOCK.len())];
+
+            let item = match Item::from_id(item_id) {
+                Some(item) => item,
+                None => {
+                    log::error!("Failed to find item for item ID: {}", item_id);
+                    continue;
+                }
+            };
+
+            let quantity = rng_manager.roll_dice(max_quantity);
+
+            if let Some(slot) = items.iter_mut().find(|slot| slot.item.id == item.id) {
+                slot.quantity += quantity;
+            } else {
+                items.push(InventorySlot { item, quantity });
+            }
+        }
+
+        Self { day, items }
+    }
+}
+
+// Price modifier for a town as a percentage (80% to 120%), based on the town ID
+pub fn price_modifier(town_id: u32) -> u32 {
+    80 + town_id % 41
+}
+
+// Price modifier for the player's standing as a percentage, 1% per point either way
+pub fn standing_modifier(standing: i32) -> u32 {
+    (100 - standing.clamp(
+        -crate::entities::player::STANDING_LIMIT,
+        crate::entities::player::STANDING_LIMIT,
+    )) as u32
+}
+
+// Price to buy an item in a town
+pub fn buy_price(item: &Item, town_id: u32, standing: i32) -> u32 {
+    (item.value * price_modifier(town_id) * standing_modifier(standing) / 10000).max(1)
+}
+
+// Price a shop will pay for an item in a town
+pub fn sell_price(item: &Item, town_id: u32, standing: i32) -> u32 {
+    (buy_price(item, town_id, 0) * (200 - standing_modifier(standing)) / 200).max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn price_modifier_stays_between_80_and_120_percent() {
+        assert_eq!(price_modifier(0), 80);
+        assert_eq!(price_modifier(40), 120);
+        assert_eq!(price_modifier(41), 80);
+        assert!((0..1000).all(|town_id| (80..=120).contains(&price_modifier(town_id))));
+    }
+
+    #[test]
+    fn prices_follow_the_town_and_never_drop_to_nothing() {
+        let ring = Item::from_id(14).unwrap();
+        let apple = Item::from_id(3).unwrap();
+
+        assert_eq!(buy_price(&ring, 40, 0), 60);
+        assert_eq!(sell_price(&ring, 40, 0), 30);
+        assert_eq!(buy_price(&apple, 0, 0), 1);
+        assert_eq!(sell_price(&apple, 0, 0), 1);
+    }
+}
@@ -0,0 +1,163 @@
+use super::manager::{BuildingType, WorldManager};
+use super::weather::WeatherType;
+
+// Const for the price of a bed for the night (before the town's price modifier)
+const REST_PRICE: u32 = 10;
+
+// Const for the price of a hot meal (before the town's price modifier)
+const MEAL_PRICE: u32 = 6;
+
+// Const for the coach fare as a percentage of the road cost
+const COACH_FARE_PERCENT: u32 = 75;
+
+// Const for the number of kinds of rumour
+const RUMOUR_KINDS: u32 = 3;
+
+// Price of a bed for the night in a town
+pub fn rest_price(town_id: u32) -> u32 {
+    (REST_PRICE * super::shop::price_modifier(town_id) / 100).max(1)
+}
+
+// Price of a hot meal in a town
+pub fn meal_price(town_id: u32) -> u32 {
+    (MEAL_PRICE * super::shop::price_modifier(town_id) / 100).max(1)
+}
+
+// Coach fare for a journey with the given road cost
+pub fn coach_fare(cost: u32) -> u32 {
+    (cost * COACH_FARE_PERCENT / 100).max(1)
+}
+
+// Get the towns a coach can go to from a town (name, fare), sorted by name
+pub fn coach_destinations(world_manager: &WorldManager, town_id: u32) -> Vec<(String, u32)> {
+    let world = match world_manager.world.as_ref() {
+        Some(world) => world,
+        None => return Vec::new(),
+    };
+
+    let origin = match world.towns.get(&town_id) {
+        Some(town) => town.name.clone(),
+        None => return Vec::new(),
+    };
+
+    let mut destinations: Vec<(String, u32)> = world
+        .towns
+        .values()
+        .filter(|town| town.id != town_id)
+        .filter_map(|town| {
+            let cost = world_manager.get_travel_cost(&origin, &town.name);
+
+            // Towns without a road have no cost, the coach doesn't go there
+            if cost > 0 {
+                Some((town.name.clone(), coach_fare(cost)))
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    destinations.sort();
+
+    destinations
+}
+
+// Generate a rumour from the world data
+pub fn generate_rumour(
+    world_manager: &mut WorldManager,
+    town_id: u32,
+    day: u32,
+    forecast: Option<WeatherType>,
+    rng_manager: &crate::core::rng::RngManager,
+) -> String {
+    let rumour = match rng_manager.roll_dice(RUMOUR_KINDS) {
+        1 => npc_rumour(world_manager, town_id, rng_manager),
+        2 => shop_rumour(world_manager, town_id, day, rng_manager),
+        _ => None,
+    };
+
+    // Everyone has an opinion on the weather
+    rumour.unwrap_or_else(|| match forecast.unwrap_or_default() {
+        WeatherType::Sunny => "\"Clear skies ahead, or so the farmers say.\"".into(),
+        WeatherType::Raining => {
+            "\"My knee's aching. There's rain on the way, mark my words.\"".into()
+        }
+        WeatherType::Windy => "\"The gulls are flying low. Wind's coming.\"".into(),
+        WeatherType::Stormy => {
+            "\"Storm's brewing out past the hills. I'd stay off the roads.\"".into()
+        }
+        WeatherType::Snowing => "\"Feel that chill? Snow's not far off.\"".into(),
+    })
+}
+
+// Rumour about an NPC in another town
+fn npc_rumour(
+    world_manager: &WorldManager,
+    town_id: u32,
+    rng_manager: &crate::core::rng::RngManager,
+) -> Option<String> {
+    let world = world_manager.world.as_ref()?;
+
+    // Sorted so the same seed hears the same rumours
+    let mut npc_ids: Vec<u32> = world
+        .npcs
+        .values()
+        .filter(|npc| npc.town_id != town_id)
+        .map(|npc| npc.id)
+        .collect();
+    npc_ids.sort();
+
+    if npc_ids.is_empty() {
+        return None;
+    }
+
+    let npc = world
+        .npcs
+        .get(&npc_ids[rng_manager.pick_index(npc_ids.len())])?;
+    let town = world.towns.get(&npc.town_id)?;
+    let building = world.buildings.get(&npc.building_id)?;
+
+    Some(format!(
+        "\"Have you met {} over in {}? You'll find them at {} most days.\"",
+        npc.name, town.name, building.name
+    ))
+}
+
+// Rumour about what a shop in another town has in stock
+fn shop_rumour(
+    world_manager: &mut WorldManager,
+    town_id: u32,
+    day: u32,
+    rng_manager: &crate::core::rng::RngManager,
+) -> Option<String> {
+    let world = world_manager.world.as_ref()?;
+
+    // Sorted so the same seed hears the same rumours
+    let mut shop_ids: Vec<u32> = world
+        .buildings
+        .values()
+        .filter(|building| building.building_type == BuildingType::Shop)
+        .filter(|building| building.town_id != town_id)
+        .map(|building| building.id)
+        .collect();
+    shop_ids.sort();
+
+    if shop_ids.is_empty() {
+        return None;
+    }
+
+    let shop_id = shop_ids[rng_manager.pick_index(shop_ids.len())];
+
+    world_manager.restock_shop(shop_id, day, rng_manager);
+
+    let stock = world_manager.shop_stock.get(&shop_id)?;
+    let slot = stock.items.get(rng_manager.pick_index(stock.items.len()))?;
+
+    let world = world_manager.world.as_ref()?;
+    let shop = world.buildings.get(&shop_id)?;
+    let town = world.towns.get(&shop.town_id)?;
+
+    Some(format!(
+        "\"{} in {} just got a delivery. {} {}, if you're quick.\"",
+        shop.name, town.name, slot.quantity, slot.item.name
+    ))
+}
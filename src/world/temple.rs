@@ -0,0 +1,28 @@
+use crate::entities::condition::BlessingType;
+
+// Const for blessings (type, price, days it lasts)
+pub const BLESSINGS: [(BlessingType, u32, u32); 2] = [
+    (BlessingType::SafeTravel, 20, 3),
+    (BlessingType::Fortune, 30, 3),
+];
+
+// Consts for temple prices (in gold, before the town's price modifier)
+const HEAL_PRICE_PER_POINT: u32 = 1;
+const CURE_PRICE: u32 = 15;
+
+// Const for the size of a donation
+pub const DONATION: u32 = 10;
+
+// Price to heal a number of points of health in a town
+pub fn heal_price(points: u32, town_id: u32) -> u32 {
+    if points == 0 {
+        return 0;
+    }
+
+    (points * HEAL_PRICE_PER_POINT * super::shop::price_modifier(town_id) / 100).max(1)
+}
+
+// Price to cure a number of ailments in a town
+pub fn cure_price(ailments: usize, town_id: u32) -> u32 {
+    ailments as u32 * CURE_PRICE * super::shop::price_modifier(town_id) / 100
+}
@@ -25,7 +25,8 @@ impl TimeManager {
         let game_time = Arc::new(RwLock::new(initial_game_time));
         let game_time_arc_clone = Arc::clone(&game_time);
 
-        self.shutdown_flag.store(false, Ordering::Relaxed);
+        // Each thread gets its own flag so a thread that is stopping can't be restarted by mistake
+        self.shutdown_flag = Arc::new(AtomicBool::new(false));
         let shutdown_flag_arc_clone = Arc::clone(&self.shutdown_flag);
 
         thread::spawn(move || {
@@ -33,17 +34,11 @@ impl TimeManager {
             let real_time_per_day = Duration::from_secs(15 * 60);
 
             // Number of ticks in one day (e.g., 900 ticks for 15 minutes)
-            let ticks_per_day = 900;
+            let ticks_per_day = TICKS_PER_DAY;
 
             // Duration of one tick in real-world time
             let tick_duration = real_time_per_day / ticks_per_day;
 
-            // Day/Night cycle
-            let dawn_ticks = (ticks_per_day as f64 * 0.25) as u32;
-            let day_ticks = (ticks_per_day as f64 * 0.5) as u32;
-            let dusk_ticks = (ticks_per_day as f64 * 0.75) as u32;
-            let _night_ticks = (ticks_per_day as f64 * 1.0) as u32;
-
             // Time tracking
             let mut current_tick = 0;
             let mut current_day = 1;
@@ -88,15 +83,7 @@ impl TimeManager {
                     }
 
                     // Determine current day/night phase
-                    if current_tick <= dawn_ticks {
-                        current_phase = Phase::Dawn
-                    } else if current_tick > dawn_ticks && current_tick <= day_ticks {
-                        current_phase = Phase::Day
-                    } else if current_tick > day_ticks && current_tick <= dusk_ticks {
-                        current_phase = Phase::Dusk
-                    } else {
-                        current_phase = Phase::Night
-                    }
+                    current_phase = Phase::from_tick(current_tick);
                 }
 
                 // Only update GameTime if something changed
@@ -119,8 +106,44 @@ impl TimeManager {
     pub fn stop(&self) {
         self.shutdown_flag.store(true, Ordering::Relaxed);
     }
+
+    // Get a copy of the current time
+    pub fn now(&self) -> Option<GameTime> {
+        self.time_arc_rwlock
+            .as_ref()
+            .and_then(|game_time| game_time.read().ok().map(|t| t.clone()))
+    }
+
+    // Get the current phase of the day
+    pub fn phase(&self) -> Phase {
+        self.now().map(|time| time.phase).unwrap_or(Phase::Day)
+    }
+
+    // Advance time by a number of ticks, restarts the time thread from the new time
+    pub fn advance(&mut self, ticks: u32) {
+        self.stop();
+
+        let mut time = match self.now() {
+            Some(time) => time,
+            None => {
+                log::error!("Failed to advance GameTime: No GameTime found.");
+                GameTime::new()
+            }
+        };
+
+        time.advance(ticks);
+
+        self.start(time);
+    }
 }
 
+// Const for number of ticks in one in-game day
+pub const TICKS_PER_DAY: u32 = 900;
+
+// Consts for showing ticks as a time of day, each day starts at dawn
+const MINUTES_PER_DAY: u32 = 24 * 60;
+const DAWN_MINUTES: u32 = 6 * 60;
+
 // Struct for Game Time
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameTime {
@@ -139,13 +162,106 @@ impl GameTime {
             phase: Phase::Dawn,
         }
     }
+
+    // Advance by a number of ticks, rolling over days and updating the phase
+    pub fn advance(&mut self, ticks: u32) {
+        self.tick += ticks;
+        self.day += self.tick / TICKS_PER_DAY;
+        self.tick %= TICKS_PER_DAY;
+        self.phase = Phase::from_tick(self.tick);
+    }
+
+    // Ticks since the game started
+    pub fn total_ticks(&self) -> u32 {
+        (self.day - 1) * TICKS_PER_DAY + self.tick
+    }
+
+    // Timestamp for the message log, the tick turned into a time of day, e.g. "Day 2, 09:36"
+    // Tick 0 is dawn, so the night runs past midnight until the next day begins
+    pub fn timestamp(&self) -> String {
+        let minutes =
+            (DAWN_MINUTES + self.tick * MINUTES_PER_DAY / TICKS_PER_DAY) % MINUTES_PER_DAY;
+
+        format!("Day {}, {:02}:{:02}", self.day, minutes / 60, minutes % 60)
+    }
 }
 
 // Enum for day/night phases
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Phase {
     Dawn,
     Day,
     Dusk,
     Night,
 }
+
+// Functions for Phase
+impl Phase {
+    // Determine the day/night phase for a tick
+    pub fn from_tick(tick: u32) -> Self {
+        // Day/Night cycle
+        let dawn_ticks = (TICKS_PER_DAY as f64 * 0.25) as u32;
+        let day_ticks = (TICKS_PER_DAY as f64 * 0.5) as u32;
+        let dusk_ticks = (TICKS_PER_DAY as f64 * 0.75) as u32;
+
+        if tick <= dawn_ticks {
+            Phase::Dawn
+        } else if tick <= day_ticks {
+            Phase::Day
+        } else if tick <= dusk_ticks {
+            Phase::Dusk
+        } else {
+            Phase::Night
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn advance_rolls_over_days() {
+        let mut time = GameTime::new();
+
+        time.advance(TICKS_PER_DAY * 2 + 100);
+
+        assert_eq!(time.day, 3);
+        assert_eq!(time.tick, 100);
+        assert_eq!(time.total_ticks(), TICKS_PER_DAY * 2 + 100);
+    }
+
+    #[test]
+    fn advance_updates_the_phase() {
+        let mut time = GameTime::new();
+
+        time.advance(TICKS_PER_DAY - 1);
+
+        assert_eq!(time.day, 1);
+        assert_eq!(time.phase, Phase::Night);
+
+        time.advance(1);
+
+        assert_eq!(time.day, 2);
+        assert_eq!(time.phase, Phase::Dawn);
+    }
+
+    #[test]
+    fn timestamp_shows_the_time_of_day() {
+        let mut time = GameTime::new();
+        assert_eq!(time.timestamp(), "Day 1, 06:00");
+
+        time.advance(400);
+        assert_eq!(time.timestamp(), "Day 1, 16:40");
+
+        // Midnight comes during the night, before the day changes at dawn
+        time.advance(275);
+        assert_eq!(time.timestamp(), "Day 1, 00:00");
+
+        time.advance(TICKS_PER_DAY + 224);
+        assert_eq!(time.timestamp(), "Day 2, 05:58");
+
+        time.advance(1);
+        assert_eq!(time.timestamp(), "Day 3, 06:00");
+    }
+}
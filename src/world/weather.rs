@@ -1,15 +1,15 @@
-use rand::Rng;
 use serde::{Deserialize, Serialize};
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, Mutex, RwLock};
 use std::thread;
 use std::time::Duration;
-use strum::EnumCount;
+use strum::{EnumCount, IntoEnumIterator};
 use strum_macros::{EnumCount, EnumIter};
 
 // Struct for Weather Manager
 pub struct WeatherManager {
     pub weather_arc_rwlock: Option<Arc<RwLock<GameWeather>>>,
+    pub rng_arc_mutex: Option<Arc<Mutex<crate::core::rng::GameRng>>>,
     shutdown_flag: Arc<AtomicBool>,
 }
 
@@ -19,16 +19,24 @@ impl WeatherManager {
     pub fn new() -> Self {
         Self {
             weather_arc_rwlock: None,
+            rng_arc_mutex: None,
             shutdown_flag: Arc::new(AtomicBool::new(false)),
         }
     }
 
     // Start weather, spawns in a new thread
-    pub fn start(&mut self, initial_game_weather: GameWeather) {
+    // Weather rolls from its own Game RNG stream so the player's rolls can be reproduced from the seed
+    pub fn start(
+        &mut self,
+        initial_game_weather: GameWeather,
+        game_rng: Arc<Mutex<crate::core::rng::GameRng>>,
+    ) {
         let game_weather = Arc::new(RwLock::new(initial_game_weather));
         let game_weather_arc_clone = Arc::clone(&game_weather);
+        let game_rng_arc_clone = Arc::clone(&game_rng);
 
-        self.shutdown_flag.store(false, Ordering::Relaxed);
+        // Each thread gets its own flag so a thread that is stopping can't be restarted by mistake
+        self.shutdown_flag = Arc::new(AtomicBool::new(false));
         let shutdown_flag_arc_clone = Arc::clone(&self.shutdown_flag);
 
         thread::spawn(move || loop {
@@ -38,18 +46,12 @@ impl WeatherManager {
             }
 
             // Roll to see if weather changes
-            if roll_dice(100) < 5 {
+            if crate::core::rng::roll_dice(&game_rng_arc_clone, 100) < 5 {
                 let mut weather = game_weather_arc_clone.write().unwrap();
 
-                // Roll to decide weather type
-                match roll_dice(WeatherType::COUNT as u32) {
-                    0 => weather.weather_type = WeatherType::Sunny,
-                    1 => weather.weather_type = WeatherType::Raining,
-                    2 => weather.weather_type = WeatherType::Windy,
-                    3 => weather.weather_type = WeatherType::Stormy,
-                    4 => weather.weather_type = WeatherType::Snowing,
-                    _ => weather.weather_type = WeatherType::Sunny,
-                }
+                // The forecast becomes the weather, then roll the next forecast
+                weather.weather_type = weather.forecast.clone();
+                weather.forecast = WeatherType::roll(&game_rng_arc_clone);
             }
 
             // 10 second sleep to prevent excessive CPU usage
@@ -57,6 +59,7 @@ impl WeatherManager {
         });
 
         self.weather_arc_rwlock = Some(game_weather);
+        self.rng_arc_mutex = Some(game_rng);
     }
 
     // Stop the weather thread
@@ -65,16 +68,12 @@ impl WeatherManager {
     }
 }
 
-// Function for dice rolls
-fn roll_dice(dice_size: u32) -> u32 {
-    let mut rng = rand::rng();
-    rng.random_range(1..=dice_size)
-}
-
 // Struct for Game Weather
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameWeather {
     pub weather_type: WeatherType,
+    #[serde(default)]
+    pub forecast: WeatherType,
 }
 
 // Functions for Game Weather
@@ -83,16 +82,70 @@ impl GameWeather {
     pub fn new() -> Self {
         Self {
             weather_type: WeatherType::Sunny,
+            forecast: WeatherType::Sunny,
         }
     }
 }
 
 // Enum for weather types
-#[derive(Debug, Clone, EnumCount, EnumIter, Serialize, Deserialize)]
+#[derive(
+    Debug, Clone, Default, PartialEq, Eq, Hash, EnumCount, EnumIter, Serialize, Deserialize,
+)]
 pub enum WeatherType {
+    #[default]
     Sunny,
     Raining,
     Windy,
     Stormy,
     Snowing,
 }
+
+// Functions for weather types
+impl WeatherType {
+    // Chance (as a percentage) of a mishap when travelling on foot in this weather
+    pub fn travel_risk(&self) -> u32 {
+        match self {
+            WeatherType::Sunny => 0,
+            WeatherType::Windy => 10,
+            WeatherType::Raining => 20,
+            WeatherType::Snowing => 30,
+            WeatherType::Stormy => 40,
+        }
+    }
+
+    // Ailment caught from a mishap in this weather
+    pub fn ailment(&self) -> crate::entities::condition::Ailment {
+        match self {
+            WeatherType::Raining | WeatherType::Snowing => {
+                crate::entities::condition::Ailment::Chill
+            }
+            _ => crate::entities::condition::Ailment::Injury,
+        }
+    }
+
+    // Roll a random weather type
+    pub fn roll(game_rng: &Mutex<crate::core::rng::GameRng>) -> Self {
+        let roll = crate::core::rng::roll_dice(game_rng, WeatherType::COUNT as u32);
+
+        WeatherType::iter()
+            .nth(roll as usize - 1)
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_weather_type_can_be_rolled() {
+        let game_rng = Mutex::new(crate::core::rng::GameRng::weather(7));
+        let mut seen = std::collections::HashSet::new();
+
+        for _ in 0..100 {
+            seen.insert(WeatherType::roll(&game_rng));
+        }
+
+        assert_eq!(seen.len(), WeatherType::COUNT);
+    }
+}